@@ -1,45 +1,38 @@
-use bip39::Mnemonic;
-use std::str::FromStr;
+use bip39::{Language, Mnemonic};
 
-fn main() {
-    println!("Generating valid BIP39 mnemonics for different word counts...\n");
-
-    // Generate 12-word mnemonic
-    let mnemonic12 = Mnemonic::generate(12).expect("Failed to generate 12-word mnemonic");
-    println!("12-word mnemonic:");
-    println!("{}", mnemonic12);
-    println!("Word count: {}", mnemonic12.word_count());
-    println!("Valid: {}\n", validate_mnemonic(&mnemonic12.to_string()));
-
-    // Generate 15-word mnemonic
-    let mnemonic15 = Mnemonic::generate(15).expect("Failed to generate 15-word mnemonic");
-    println!("15-word mnemonic:");
-    println!("{}", mnemonic15);
-    println!("Word count: {}", mnemonic15.word_count());
-    println!("Valid: {}\n", validate_mnemonic(&mnemonic15.to_string()));
+const LANGUAGES: [Language; 10] = [
+    Language::English,
+    Language::Japanese,
+    Language::Korean,
+    Language::Spanish,
+    Language::ChineseSimplified,
+    Language::ChineseTraditional,
+    Language::French,
+    Language::Italian,
+    Language::Czech,
+    Language::Portuguese,
+];
 
-    // Generate 18-word mnemonic
-    let mnemonic18 = Mnemonic::generate(18).expect("Failed to generate 18-word mnemonic");
-    println!("18-word mnemonic:");
-    println!("{}", mnemonic18);
-    println!("Word count: {}", mnemonic18.word_count());
-    println!("Valid: {}\n", validate_mnemonic(&mnemonic18.to_string()));
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
 
-    // Generate 21-word mnemonic
-    let mnemonic21 = Mnemonic::generate(21).expect("Failed to generate 21-word mnemonic");
-    println!("21-word mnemonic:");
-    println!("{}", mnemonic21);
-    println!("Word count: {}", mnemonic21.word_count());
-    println!("Valid: {}\n", validate_mnemonic(&mnemonic21.to_string()));
+fn main() {
+    println!("Generating valid BIP39 mnemonics for every supported language and word count...\n");
 
-    // Generate 24-word mnemonic
-    let mnemonic24 = Mnemonic::generate(24).expect("Failed to generate 24-word mnemonic");
-    println!("24-word mnemonic:");
-    println!("{}", mnemonic24);
-    println!("Word count: {}", mnemonic24.word_count());
-    println!("Valid: {}\n", validate_mnemonic(&mnemonic24.to_string()));
+    for language in LANGUAGES {
+        for word_count in WORD_COUNTS {
+            let mnemonic = Mnemonic::generate_in(language, word_count)
+                .unwrap_or_else(|_| panic!("Failed to generate a {}-word {:?} mnemonic", word_count, language));
+            println!("{:?} {}-word mnemonic:", language, word_count);
+            println!("{}", mnemonic);
+            println!("Word count: {}", mnemonic.word_count());
+            println!("Valid: {}\n", validate_mnemonic(&mnemonic.to_string(), language));
+        }
+    }
 }
 
-fn validate_mnemonic(mnemonic_str: &str) -> bool {
-    Mnemonic::from_str(mnemonic_str).is_ok()
-}
\ No newline at end of file
+/// Validates `mnemonic_str` against `language`'s wordlist specifically, so a
+/// phrase whose words belong to a different language is rejected rather
+/// than silently assumed to be English.
+fn validate_mnemonic(mnemonic_str: &str, language: Language) -> bool {
+    Mnemonic::parse_in(language, mnemonic_str).is_ok()
+}
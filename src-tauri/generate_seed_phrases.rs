@@ -1,45 +1,38 @@
-use bip39::Mnemonic;
-use std::str::FromStr;
+use bip39::{Language, Mnemonic};
 
-fn main() {
-    println!("Generating valid BIP39 seed phrases for different word counts...\n");
-
-    // Generate 12-word seed phrase
-    let seed_phrase12 = Mnemonic::generate(12).expect("Failed to generate 12-word seed phrase");
-    println!("12-word seed phrase:");
-    println!("{}", seed_phrase12);
-    println!("Word count: {}", seed_phrase12.word_count());
-    println!("Valid: {}\n", validate_seed_phrase(&seed_phrase12.to_string()));
-
-    // Generate 15-word seed phrase
-    let seed_phrase15 = Mnemonic::generate(15).expect("Failed to generate 15-word seed phrase");
-    println!("15-word seed phrase:");
-    println!("{}", seed_phrase15);
-    println!("Word count: {}", seed_phrase15.word_count());
-    println!("Valid: {}\n", validate_seed_phrase(&seed_phrase15.to_string()));
+const LANGUAGES: [Language; 10] = [
+    Language::English,
+    Language::Japanese,
+    Language::Korean,
+    Language::Spanish,
+    Language::ChineseSimplified,
+    Language::ChineseTraditional,
+    Language::French,
+    Language::Italian,
+    Language::Czech,
+    Language::Portuguese,
+];
 
-    // Generate 18-word seed phrase
-    let seed_phrase18 = Mnemonic::generate(18).expect("Failed to generate 18-word seed phrase");
-    println!("18-word seed phrase:");
-    println!("{}", seed_phrase18);
-    println!("Word count: {}", seed_phrase18.word_count());
-    println!("Valid: {}\n", validate_seed_phrase(&seed_phrase18.to_string()));
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
 
-    // Generate 21-word seed phrase
-    let seed_phrase21 = Mnemonic::generate(21).expect("Failed to generate 21-word seed phrase");
-    println!("21-word seed phrase:");
-    println!("{}", seed_phrase21);
-    println!("Word count: {}", seed_phrase21.word_count());
-    println!("Valid: {}\n", validate_seed_phrase(&seed_phrase21.to_string()));
+fn main() {
+    println!("Generating valid BIP39 seed phrases for every supported language and word count...\n");
 
-    // Generate 24-word seed phrase
-    let seed_phrase24 = Mnemonic::generate(24).expect("Failed to generate 24-word seed phrase");
-    println!("24-word seed phrase:");
-    println!("{}", seed_phrase24);
-    println!("Word count: {}", seed_phrase24.word_count());
-    println!("Valid: {}\n", validate_seed_phrase(&seed_phrase24.to_string()));
+    for language in LANGUAGES {
+        for word_count in WORD_COUNTS {
+            let seed_phrase = Mnemonic::generate_in(language, word_count)
+                .unwrap_or_else(|_| panic!("Failed to generate a {}-word {:?} seed phrase", word_count, language));
+            println!("{:?} {}-word seed phrase:", language, word_count);
+            println!("{}", seed_phrase);
+            println!("Word count: {}", seed_phrase.word_count());
+            println!("Valid: {}\n", validate_seed_phrase(&seed_phrase.to_string(), language));
+        }
+    }
 }
 
-fn validate_seed_phrase(seed_phrase_str: &str) -> bool {
-    Mnemonic::from_str(seed_phrase_str).is_ok()
-}
\ No newline at end of file
+/// Validates `seed_phrase_str` against `language`'s wordlist specifically,
+/// so a phrase whose words belong to a different language is rejected
+/// rather than silently assumed to be English.
+fn validate_seed_phrase(seed_phrase_str: &str, language: Language) -> bool {
+    Mnemonic::parse_in(language, seed_phrase_str).is_ok()
+}
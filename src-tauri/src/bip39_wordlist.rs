@@ -0,0 +1,202 @@
+use bip39::Language;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+
+/// All BIP39 wordlists bundled with the `bip39` crate that we expose to the UI.
+pub const SUPPORTED_LANGUAGES: &[Language] = &[
+    Language::English,
+    Language::Japanese,
+    Language::Korean,
+    Language::Spanish,
+    Language::French,
+    Language::Italian,
+    Language::Czech,
+    Language::Portuguese,
+    Language::ChineseSimplified,
+    Language::ChineseTraditional,
+];
+
+/// Parses a user-facing language name (e.g. from a Tauri command argument) into
+/// the corresponding BIP39 `Language`. Matching is case-insensitive.
+pub fn parse_language(name: &str) -> Option<Language> {
+    match name.to_lowercase().as_str() {
+        "english" => Some(Language::English),
+        "japanese" => Some(Language::Japanese),
+        "korean" => Some(Language::Korean),
+        "spanish" => Some(Language::Spanish),
+        "french" => Some(Language::French),
+        "italian" => Some(Language::Italian),
+        "czech" => Some(Language::Czech),
+        "portuguese" => Some(Language::Portuguese),
+        "chinese_simplified" | "chinesesimplified" => Some(Language::ChineseSimplified),
+        "chinese_traditional" | "chinesetraditional" => Some(Language::ChineseTraditional),
+        _ => None,
+    }
+}
+
+/// The inverse of `parse_language`, for commands that report back which
+/// language they resolved/detected.
+pub fn language_name(language: Language) -> &'static str {
+    match language {
+        Language::English => "english",
+        Language::Japanese => "japanese",
+        Language::Korean => "korean",
+        Language::Spanish => "spanish",
+        Language::French => "french",
+        Language::Italian => "italian",
+        Language::Czech => "czech",
+        Language::Portuguese => "portuguese",
+        Language::ChineseSimplified => "chinese_simplified",
+        Language::ChineseTraditional => "chinese_traditional",
+        _ => "english",
+    }
+}
+
+fn wordlist_for(language: Language) -> HashSet<&'static str> {
+    language.word_list().iter().copied().collect()
+}
+
+pub fn is_valid_bip39_word(word: &str) -> bool {
+    is_valid_bip39_word_in(word, Language::English)
+}
+
+pub fn is_valid_bip39_word_in(word: &str, language: Language) -> bool {
+    // NFKD-normalize first: accented wordlists (Spanish, French, Italian,
+    // Portuguese, Czech) are bundled in precomposed form, but user input can
+    // arrive precomposed or decomposed depending on OS/keyboard, and the two
+    // forms must compare equal.
+    let normalized: String = word.nfkd().collect();
+    wordlist_for(language).contains(normalized.as_str())
+}
+
+pub fn get_bip39_suggestions(prefix: &str, limit: usize) -> Vec<String> {
+    get_bip39_suggestions_in(prefix, limit, Language::English)
+}
+
+pub fn get_bip39_suggestions_in(prefix: &str, limit: usize, language: Language) -> Vec<String> {
+    // Every official BIP39 wordlist guarantees a word can be identified from
+    // its first `unique_prefix_len` characters alone, so once the user has
+    // typed that many we already know the exact word.
+    if let Some(word) = resolve_word_by_unique_prefix(prefix, language) {
+        return vec![word];
+    }
+
+    let prefix_normalized: String = prefix.nfkd().collect::<String>().to_lowercase();
+    language
+        .word_list()
+        .iter()
+        .filter(|word| word.starts_with(prefix_normalized.as_str()))
+        .take(limit)
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// How many leading characters of a BIP39 word are guaranteed unique within
+/// `language`'s wordlist. The official English/Spanish/French/Italian/
+/// Czech/Portuguese lists guarantee uniqueness at 4 characters; the Chinese
+/// lists use a single unique hanzi per word.
+pub fn unique_prefix_len(language: Language) -> usize {
+    match language {
+        Language::ChineseSimplified | Language::ChineseTraditional => 1,
+        _ => 4,
+    }
+}
+
+/// Resolves `prefix` to the one word it must be, if `prefix` is already at
+/// least `unique_prefix_len(language)` characters long. Returns `None` if
+/// the prefix is too short to be unique yet, or matches no word.
+pub fn resolve_word_by_unique_prefix(prefix: &str, language: Language) -> Option<String> {
+    let required_len = unique_prefix_len(language);
+    if prefix.chars().count() < required_len {
+        return None;
+    }
+
+    let prefix_normalized: String = prefix.nfkd().collect::<String>().to_lowercase();
+    language
+        .word_list()
+        .iter()
+        .find(|word| word.starts_with(prefix_normalized.as_str()))
+        .map(|word| word.to_string())
+}
+
+/// The outcome of auto-detecting which BIP39 wordlist a seed phrase was
+/// written in, for a dedicated "detect/select language" command.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LanguageDetectionResult {
+    /// `None` if not a single word in the phrase matched any bundled wordlist.
+    pub language: Option<String>,
+    pub matched_words: usize,
+    pub total_words: usize,
+    pub all_words_matched: bool,
+}
+
+/// Detects the language of `phrase` and reports match quality, for callers
+/// that want to show the user what was detected before committing to it
+/// (as opposed to `resolve_language`, which silently falls back to English).
+pub fn detect_seed_phrase_language(phrase: &str) -> LanguageDetectionResult {
+    let total_words = phrase.split_whitespace().count();
+    match detect_language(phrase) {
+        Some(language) => {
+            let list = wordlist_for(language);
+            let matched_words = phrase
+                .split_whitespace()
+                .filter(|word| list.contains(word))
+                .count();
+            LanguageDetectionResult {
+                language: Some(language_name(language).to_string()),
+                matched_words,
+                total_words,
+                all_words_matched: matched_words == total_words,
+            }
+        }
+        None => LanguageDetectionResult {
+            language: None,
+            matched_words: 0,
+            total_words,
+            all_words_matched: false,
+        },
+    }
+}
+
+/// Scores `phrase` against every bundled wordlist and returns the best match,
+/// i.e. the language whose wordlist contains the most of the phrase's words.
+/// Ties are broken in favor of the language where *every* word is present.
+pub fn detect_language(phrase: &str) -> Option<Language> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let mut best_language = Language::English;
+    let mut best_matches = 0usize;
+    let mut best_all_present = false;
+
+    for &language in SUPPORTED_LANGUAGES {
+        let list = wordlist_for(language);
+        let matches = words.iter().filter(|word| list.contains(*word)).count();
+        let all_present = matches == words.len();
+
+        let is_better = matches > best_matches || (matches == best_matches && all_present && !best_all_present);
+        if is_better {
+            best_language = language;
+            best_matches = matches;
+            best_all_present = all_present;
+        }
+    }
+
+    if best_matches == 0 {
+        None
+    } else {
+        Some(best_language)
+    }
+}
+
+/// Resolves the language to validate against: an explicit name wins, otherwise
+/// we fall back to auto-detection against `phrase`, and finally to English.
+pub fn resolve_language(requested: Option<&str>, phrase: &str) -> Language {
+    requested
+        .and_then(parse_language)
+        .or_else(|| detect_language(phrase))
+        .unwrap_or(Language::English)
+}
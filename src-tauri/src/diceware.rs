@@ -1,6 +1,9 @@
+use bip39::Language;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
@@ -9,57 +12,278 @@ use crate::ValidationResult;
 // EFF Large Wordlist embedded as a fallback
 const EFF_WORDLIST: &str = include_str!("../../resources/eff_large_wordlist.txt");
 
-pub fn load_eff_wordlist() -> Result<HashSet<String>, Box<dyn std::error::Error>> {
-    let mut wordlist = HashSet::new();
-    
-    // Try to load from external file first
+/// Reads the EFF large wordlist's raw text: an external file first (checked
+/// at each of a few likely working directories), falling back to the copy
+/// embedded in the binary at compile time.
+fn eff_wordlist_content() -> Cow<'static, str> {
     let external_paths = [
         "resources/eff_large_wordlist.txt",
         "../resources/eff_large_wordlist.txt",
         "../../resources/eff_large_wordlist.txt",
     ];
-    
-    let mut loaded_from_file = false;
+
     for path in &external_paths {
         if Path::new(path).exists() {
-            match fs::read_to_string(path) {
-                Ok(content) => {
-                    for line in content.lines() {
-                        let line = line.trim();
-                        if !line.is_empty() && !line.starts_with('#') {
-                            // EFF wordlist format: "11111 abacus"
-                            if let Some(word) = line.split_whitespace().nth(1) {
-                                wordlist.insert(word.to_lowercase());
-                            }
-                        }
-                    }
-                    loaded_from_file = true;
-                    break;
-                }
-                Err(_) => continue,
+            if let Ok(content) = fs::read_to_string(path) {
+                return Cow::Owned(content);
             }
         }
     }
-    
-    // Fallback to embedded wordlist
-    if !loaded_from_file {
-        for line in EFF_WORDLIST.lines() {
-            let line = line.trim();
-            if !line.is_empty() && !line.starts_with('#') {
-                if let Some(word) = line.split_whitespace().nth(1) {
-                    wordlist.insert(word.to_lowercase());
-                }
-            }
-        }
+
+    Cow::Borrowed(EFF_WORDLIST)
+}
+
+/// Splits one EFF wordlist line ("11111 abacus") into its five-digit dice
+/// code and word, skipping blank/comment lines.
+fn parse_eff_line(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
     }
-    
+    let mut parts = line.split_whitespace();
+    let code = parts.next()?;
+    let word = parts.next()?;
+    Some((code, word))
+}
+
+pub fn load_eff_wordlist() -> Result<HashSet<String>, Box<dyn std::error::Error>> {
+    let wordlist: HashSet<String> = eff_wordlist_content()
+        .lines()
+        .filter_map(parse_eff_line)
+        .map(|(_code, word)| word.to_lowercase())
+        .collect();
+
     if wordlist.is_empty() {
         return Err("Failed to load any words from wordlist".into());
     }
-    
+
     Ok(wordlist)
 }
 
+/// Maps each EFF dice code (five digits, each 1-6) to its word, for users who
+/// want to roll physical dice instead of trusting the machine's CSPRNG (see
+/// `passphrase_from_dice_rolls`). Built from the same source as
+/// `load_eff_wordlist`, so `dice_map.values()` is exactly the word universe
+/// `load_eff_wordlist` returns — entropy accounting (`calculate_passphrase_entropy`)
+/// is the same for a dice-rolled and a CSPRNG-generated passphrase.
+pub fn load_eff_dice_map() -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
+    let dice_map: HashMap<String, String> = eff_wordlist_content()
+        .lines()
+        .filter_map(parse_eff_line)
+        .map(|(code, word)| (code.to_string(), word.to_lowercase()))
+        .collect();
+
+    if dice_map.is_empty() {
+        return Err("Failed to load any words from wordlist".into());
+    }
+
+    Ok(dice_map)
+}
+
+/// Converts rolls of physical dice into a diceware passphrase, for a user
+/// who doesn't trust the machine's RNG. Each element of `rolls` is five dice
+/// results (each 1-6) that together index exactly one word in `dice_map`, the
+/// same way the EFF large wordlist is meant to be read by hand.
+pub fn passphrase_from_dice_rolls(
+    rolls: &[[u8; 5]],
+    dice_map: &HashMap<String, String>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if rolls.is_empty() {
+        return Err("At least one dice roll is required".into());
+    }
+
+    let mut words = Vec::with_capacity(rolls.len());
+    for roll in rolls {
+        if roll.iter().any(|&digit| !(1..=6).contains(&digit)) {
+            return Err(format!("Dice rolls must each be 1-6, got {:?}", roll).into());
+        }
+
+        let code: String = roll.iter().map(|digit| digit.to_string()).collect();
+        let word = dice_map
+            .get(&code)
+            .ok_or_else(|| format!("No word found for dice code {}", code))?;
+        words.push(word.clone());
+    }
+
+    Ok(words.join(" "))
+}
+
+/// Which wordlist a generated or validated passphrase is measured against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WordlistProfile {
+    /// The bundled EFF long wordlist (`AppState::wordlist`).
+    Eff,
+    /// The English BIP39 wordlist, for users who want their passphrase to
+    /// share a wordlist with their seed phrase.
+    Bip39,
+    /// A list the user supplied themselves, loaded into `AppState::custom_wordlist`.
+    Custom,
+}
+
+/// Resolves a `WordlistProfile` to the `HashSet` it refers to. Borrows where
+/// possible (EFF, custom) and builds the set on demand for BIP39, since
+/// `bip39::Language::word_list()` returns `&'static str` slices, not a set.
+pub fn resolve_wordlist<'a>(
+    profile: WordlistProfile,
+    eff_wordlist: &'a HashSet<String>,
+    custom_wordlist: Option<&'a HashSet<String>>,
+) -> Result<Cow<'a, HashSet<String>>, Box<dyn std::error::Error>> {
+    match profile {
+        WordlistProfile::Eff => Ok(Cow::Borrowed(eff_wordlist)),
+        WordlistProfile::Bip39 => Ok(Cow::Owned(
+            Language::English
+                .word_list()
+                .iter()
+                .map(|word| word.to_string())
+                .collect(),
+        )),
+        WordlistProfile::Custom => custom_wordlist
+            .map(Cow::Borrowed)
+            .ok_or_else(|| "no custom wordlist has been loaded".into()),
+    }
+}
+
+/// A generated passphrase together with enough detail for the UI to explain
+/// how strong it actually is.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PassphraseStrengthResult {
+    pub passphrase: String,
+    pub wordlist_profile: WordlistProfile,
+    pub wordlist_size: usize,
+    pub word_count: usize,
+    pub bits_per_word: f64,
+    pub total_entropy_bits: f64,
+    /// Human-readable estimate of how long an offline attacker would need,
+    /// assuming they must pay the app's real KDF cost per guess.
+    pub estimated_offline_crack_time: String,
+}
+
+/// Conservative assumption for a well-resourced offline attacker's raw guess
+/// rate before the KDF's iteration count slows them down. Used only to turn
+/// entropy bits into a human "time to crack" figure; not a precise model.
+const ASSUMED_RAW_GUESSES_PER_SECOND: f64 = 10_000_000_000.0;
+
+/// Average-case seconds to brute-force a secret of `total_entropy_bits`,
+/// given that every guess costs `kdf_iterations` rounds of the app's KDF.
+pub fn estimate_offline_guess_seconds(total_entropy_bits: f64, kdf_iterations: u32) -> f64 {
+    let guesses_per_second = ASSUMED_RAW_GUESSES_PER_SECOND / (kdf_iterations.max(1) as f64);
+    let average_guesses = 2f64.powf(total_entropy_bits) / 2.0;
+    average_guesses / guesses_per_second
+}
+
+/// Renders a guess-time estimate in seconds as a human-readable duration.
+pub fn describe_crack_time(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 60.0 * MINUTE;
+    const DAY: f64 = 24.0 * HOUR;
+    const YEAR: f64 = 365.25 * DAY;
+
+    if !seconds.is_finite() || seconds > YEAR * 1_000_000.0 {
+        "effectively uncrackable (> 1,000,000 years)".to_string()
+    } else if seconds >= YEAR {
+        format!("{:.1} years", seconds / YEAR)
+    } else if seconds >= DAY {
+        format!("{:.1} days", seconds / DAY)
+    } else if seconds >= HOUR {
+        format!("{:.1} hours", seconds / HOUR)
+    } else if seconds >= MINUTE {
+        format!("{:.1} minutes", seconds / MINUTE)
+    } else {
+        format!("{:.1} seconds", seconds.max(0.0))
+    }
+}
+
+/// Generates a passphrase from the requested wordlist profile and reports
+/// its strength: per-word and total entropy, plus an estimated offline
+/// guess time given the app's real KDF cost.
+pub fn generate_passphrase_with_strength(
+    word_count: usize,
+    profile: WordlistProfile,
+    eff_wordlist: &HashSet<String>,
+    custom_wordlist: Option<&HashSet<String>>,
+    kdf_iterations: u32,
+) -> Result<PassphraseStrengthResult, Box<dyn std::error::Error>> {
+    let wordlist = resolve_wordlist(profile, eff_wordlist, custom_wordlist)?;
+    let passphrase = generate_diceware_passphrase(word_count, &wordlist)?;
+    let bits_per_word = calculate_passphrase_entropy(1, wordlist.len());
+    let total_entropy_bits = calculate_passphrase_entropy(word_count, wordlist.len());
+    let estimated_offline_crack_time = describe_crack_time(estimate_offline_guess_seconds(
+        total_entropy_bits,
+        kdf_iterations,
+    ));
+
+    Ok(PassphraseStrengthResult {
+        passphrase,
+        wordlist_profile: profile,
+        wordlist_size: wordlist.len(),
+        word_count,
+        bits_per_word,
+        total_entropy_bits,
+        estimated_offline_crack_time,
+    })
+}
+
+/// Converts physical dice rolls into a passphrase and reports its strength,
+/// the dice-roll counterpart to `generate_passphrase_with_strength`. Since
+/// `dice_map` is built from the same EFF wordlist, the entropy accounting
+/// (`wordlist_size`, `bits_per_word`) is identical to the CSPRNG path.
+pub fn passphrase_from_dice_rolls_with_strength(
+    rolls: &[[u8; 5]],
+    dice_map: &HashMap<String, String>,
+    kdf_iterations: u32,
+) -> Result<PassphraseStrengthResult, Box<dyn std::error::Error>> {
+    let passphrase = passphrase_from_dice_rolls(rolls, dice_map)?;
+    let word_count = rolls.len();
+    let wordlist_size = dice_map.len();
+    let bits_per_word = calculate_passphrase_entropy(1, wordlist_size);
+    let total_entropy_bits = calculate_passphrase_entropy(word_count, wordlist_size);
+    let estimated_offline_crack_time = describe_crack_time(estimate_offline_guess_seconds(
+        total_entropy_bits,
+        kdf_iterations,
+    ));
+
+    Ok(PassphraseStrengthResult {
+        passphrase,
+        wordlist_profile: WordlistProfile::Eff,
+        wordlist_size,
+        word_count,
+        bits_per_word,
+        total_entropy_bits,
+        estimated_offline_crack_time,
+    })
+}
+
+/// The target-entropy counterpart to `generate_passphrase_with_strength`:
+/// picks the minimum word count that reaches `target_bits` instead of
+/// taking a fixed word count.
+pub fn generate_passphrase_for_target_entropy_with_strength(
+    target_bits: f64,
+    profile: WordlistProfile,
+    eff_wordlist: &HashSet<String>,
+    custom_wordlist: Option<&HashSet<String>>,
+    kdf_iterations: u32,
+) -> Result<PassphraseStrengthResult, Box<dyn std::error::Error>> {
+    let wordlist = resolve_wordlist(profile, eff_wordlist, custom_wordlist)?;
+    let (passphrase, total_entropy_bits) = generate_for_target_entropy(target_bits, &wordlist)?;
+    let word_count = passphrase.split_whitespace().count();
+    let bits_per_word = calculate_passphrase_entropy(1, wordlist.len());
+    let estimated_offline_crack_time = describe_crack_time(estimate_offline_guess_seconds(
+        total_entropy_bits,
+        kdf_iterations,
+    ));
+
+    Ok(PassphraseStrengthResult {
+        passphrase,
+        wordlist_profile: profile,
+        wordlist_size: wordlist.len(),
+        word_count,
+        bits_per_word,
+        total_entropy_bits,
+        estimated_offline_crack_time,
+    })
+}
+
 pub fn generate_diceware_passphrase(
     word_count: usize,
     wordlist: &HashSet<String>,
@@ -89,7 +313,53 @@ pub fn generate_diceware_passphrase(
     Ok(selected_words.join(" "))
 }
 
-pub fn validate_passphrase(passphrase: &str, wordlist: &HashSet<String>) -> ValidationResult {
+/// Above this many words, generating for a target entropy is almost
+/// certainly a sign the wordlist is too small (or the target too high) to
+/// be a reasonable ask, rather than something the user actually wants typed
+/// out and memorized.
+const MAX_TARGET_ENTROPY_WORD_COUNT: usize = 40;
+
+/// Generates the fewest words from `wordlist` needed to reach at least
+/// `target_bits` of entropy (e.g. 128 bits, the common floor for protecting
+/// a 24-word seed), instead of making the caller hand-compute a word count.
+/// Returns the passphrase together with its actual achieved entropy, which
+/// is always >= `target_bits` since word count is rounded up.
+pub fn generate_for_target_entropy(
+    target_bits: f64,
+    wordlist: &HashSet<String>,
+) -> Result<(String, f64), Box<dyn std::error::Error>> {
+    if wordlist.is_empty() {
+        return Err("Wordlist is empty".into());
+    }
+    if !target_bits.is_finite() || target_bits <= 0.0 {
+        return Err("Target entropy must be a positive number of bits".into());
+    }
+
+    let bits_per_word = calculate_passphrase_entropy(1, wordlist.len());
+    let word_count = (target_bits / bits_per_word).ceil().max(1.0) as usize;
+
+    if word_count > MAX_TARGET_ENTROPY_WORD_COUNT {
+        return Err(format!(
+            "Reaching {:.0} bits of entropy from a {}-word list would need {} words (max {}); use a larger wordlist",
+            target_bits,
+            wordlist.len(),
+            word_count,
+            MAX_TARGET_ENTROPY_WORD_COUNT
+        )
+        .into());
+    }
+
+    let passphrase = generate_diceware_passphrase(word_count, wordlist)?;
+    let achieved_entropy = calculate_passphrase_entropy(word_count, wordlist.len());
+
+    Ok((passphrase, achieved_entropy))
+}
+
+pub fn validate_passphrase(
+    passphrase: &str,
+    wordlist: &HashSet<String>,
+    minimum_entropy_bits: Option<f64>,
+) -> ValidationResult {
     let words: Vec<&str> = passphrase.split_whitespace().collect();
     let mut errors = Vec::new();
     let mut valid_words = Vec::new();
@@ -126,7 +396,20 @@ pub fn validate_passphrase(passphrase: &str, wordlist: &HashSet<String>) -> Vali
     if unique_words.len() != words.len() {
         errors.push("Passphrase contains duplicate words, which reduces security".to_string());
     }
-    
+
+    // Check against a minimum entropy requirement, if one was given
+    if let Some(minimum_entropy_bits) = minimum_entropy_bits {
+        let entropy_bits = calculate_passphrase_entropy(valid_words.len(), wordlist.len());
+        if entropy_bits < minimum_entropy_bits {
+            errors.push(format!(
+                "Passphrase has {:.1} bits of entropy; needs {:.1} more bits to reach the {:.0}-bit minimum",
+                entropy_bits,
+                minimum_entropy_bits - entropy_bits,
+                minimum_entropy_bits
+            ));
+        }
+    }
+
     ValidationResult {
         is_valid: errors.is_empty(),
         errors,
@@ -162,7 +445,7 @@ mod tests {
     #[test]
     fn test_validate_valid_passphrase() {
         let wordlist = create_test_wordlist();
-        let result = validate_passphrase("correct horse battery", &wordlist);
+        let result = validate_passphrase("correct horse battery", &wordlist, None);
         
         assert!(result.is_valid);
         assert_eq!(result.valid_words.len(), 3);
@@ -172,7 +455,7 @@ mod tests {
     #[test]
     fn test_validate_invalid_word() {
         let wordlist = create_test_wordlist();
-        let result = validate_passphrase("correct horse invalid", &wordlist);
+        let result = validate_passphrase("correct horse invalid", &wordlist, None);
         
         assert!(!result.is_valid);
         assert_eq!(result.valid_words.len(), 2);
@@ -183,7 +466,7 @@ mod tests {
     #[test]
     fn test_validate_too_few_words() {
         let wordlist = create_test_wordlist();
-        let result = validate_passphrase("correct horse", &wordlist);
+        let result = validate_passphrase("correct horse", &wordlist, None);
         
         assert!(!result.is_valid);
         assert!(result.errors.iter().any(|e| e.contains("at least 3 words")));
@@ -0,0 +1,129 @@
+//! Word-by-word interactive mnemonic entry over stdin/stdout: each word is
+//! validated against the wordlist (with unique-prefix autocomplete - BIP39
+//! words are uniquely identified by their first four letters) as it's
+//! typed, instead of only validating the whole phrase after it's fully
+//! entered. Once every word is in, the full phrase is checksum-verified;
+//! a failure re-prompts from the top rather than leaving a partially
+//! trusted mnemonic around. This is for safe manual recovery - e.g.
+//! re-entering a paper backup - not for the GUI, which validates as the
+//! user types via `validate_mnemonic`.
+
+use std::io::{self, BufRead, Write};
+
+use bip39::Language;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::mnemonic::{Mnemonic, MnemonicError, SeedPhraseValidator};
+
+const MAX_RETRIES: u32 = 3;
+const AUTOCOMPLETE_PREFIX_LEN: usize = 4;
+
+#[derive(Error, Debug)]
+pub enum InteractiveError {
+    #[error("input stream closed before a mnemonic could be entered")]
+    InputClosed,
+
+    #[error("gave up after {0} failed checksum attempts")]
+    TooManyAttempts(u32),
+
+    #[error(transparent)]
+    Mnemonic(#[from] MnemonicError),
+}
+
+impl Serialize for InteractiveError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Prompts for a mnemonic word-by-word over stdin/stdout, accepting any
+/// length in `word_count_choices` (e.g. `&[12, 24]`). Re-prompts the whole
+/// phrase, up to `MAX_RETRIES` times, if the finished phrase fails its
+/// BIP39 checksum.
+pub fn prompt_mnemonic(word_count_choices: &[usize]) -> Result<Mnemonic, InteractiveError> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    let word_count = prompt_word_count(word_count_choices, &mut lines)?;
+    let validator = SeedPhraseValidator::new();
+
+    for attempt in 1..=MAX_RETRIES {
+        let words = prompt_words(word_count, &mut lines)?;
+        let phrase = words.join(" ");
+
+        if validator.check_checksum(&phrase) {
+            return Ok(Mnemonic { phrase, word_count, language: "english".to_string() });
+        }
+
+        println!(
+            "Checksum validation failed - one of these {} words doesn't belong in this combination (attempt {} of {}).\n",
+            word_count, attempt, MAX_RETRIES
+        );
+    }
+
+    Err(InteractiveError::TooManyAttempts(MAX_RETRIES))
+}
+
+fn prompt_word_count(choices: &[usize], lines: &mut io::Lines<io::StdinLock>) -> Result<usize, InteractiveError> {
+    let options = choices.iter().map(|choice| choice.to_string()).collect::<Vec<_>>().join("/");
+    loop {
+        print!("Mnemonic length ({}): ", options);
+        io::stdout().flush().ok();
+
+        let input = lines.next().ok_or(InteractiveError::InputClosed)?.map_err(|_| InteractiveError::InputClosed)?;
+        match input.trim().parse::<usize>() {
+            Ok(count) if choices.contains(&count) => return Ok(count),
+            _ => println!("Please enter one of: {}", options),
+        }
+    }
+}
+
+fn prompt_words(word_count: usize, lines: &mut io::Lines<io::StdinLock>) -> Result<Vec<String>, InteractiveError> {
+    let wordlist = Language::English.word_list();
+    let mut words = Vec::with_capacity(word_count);
+
+    for index in 1..=word_count {
+        loop {
+            print!("Word {}/{}: ", index, word_count);
+            io::stdout().flush().ok();
+
+            let input = lines.next().ok_or(InteractiveError::InputClosed)?.map_err(|_| InteractiveError::InputClosed)?;
+            let typed = input.trim().to_lowercase();
+
+            match resolve_word(&typed, wordlist) {
+                Some(word) => {
+                    words.push(word.to_string());
+                    break;
+                }
+                None => println!("'{}' doesn't match exactly one wordlist entry - try again.", typed),
+            }
+        }
+    }
+
+    Ok(words)
+}
+
+/// Resolves `typed` to exactly one wordlist entry: an exact match always
+/// wins, otherwise a unique four-letter-prefix match autocompletes it
+/// (BIP39 guarantees no two words in a wordlist share their first four
+/// letters).
+fn resolve_word<'a>(typed: &str, wordlist: &[&'a str]) -> Option<&'a str> {
+    if let Some(&exact) = wordlist.iter().find(|&&word| word == typed) {
+        return Some(exact);
+    }
+
+    if typed.chars().count() < AUTOCOMPLETE_PREFIX_LEN {
+        return None;
+    }
+    let prefix: String = typed.chars().take(AUTOCOMPLETE_PREFIX_LEN).collect();
+    let mut matches = wordlist.iter().filter(|word| word.starts_with(prefix.as_str()));
+    let first = *matches.next()?;
+    match matches.next() {
+        Some(_) => None,
+        None => Some(first),
+    }
+}
@@ -1,22 +1,33 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use tauri::State;
 use thiserror::Error;
 use chrono::{DateTime, Utc};
 use base64::{engine::general_purpose, Engine as _};
-use aes_gcm::{KeyInit, aead::Aead};
+use bip39::Language;
 
 pub mod crypto;
 pub mod diceware;
 pub mod seed_phrase;
 pub mod network;
 pub mod bip39_wordlist;
+pub mod wallet;
+pub mod steganography;
+pub mod streaming;
+pub mod multi_recipient;
+pub mod raw_mnemonic;
+pub mod shard;
+pub mod interactive;
+#[cfg(feature = "qrcode")]
+pub mod qr;
 
 use crypto::*;
 use diceware::*;
 use seed_phrase::*;
 use network::*;
 use bip39_wordlist::*;
+use wallet::{derive_wallet_descriptor as derive_wallet_descriptor_offline, WalletDescriptor, WalletDeriveError};
+use multi_recipient::*;
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -34,6 +45,25 @@ pub enum AppError {
     SeedPhraseError(String),
     #[error("Crypto operation failed: {0}")]
     CryptoError(#[from] crypto::CryptoError),
+    #[error("Wallet derivation failed: {0}")]
+    WalletError(#[from] WalletDeriveError),
+    #[error("Corrupt or wrong file: {0}")]
+    CorruptFile(String),
+    #[error("Steganography operation failed: {0}")]
+    StegoError(#[from] steganography::StegoError),
+    #[error("Multi-recipient operation failed: {0}")]
+    RecipientError(#[from] multi_recipient::MultiRecipientError),
+}
+
+/// Maps a `CryptoError` from parsing a container header, surfacing a header
+/// checksum failure as the distinct `CorruptFile` variant instead of the
+/// generic `CryptoError` passthrough, so the frontend can tell "this isn't
+/// even the right kind of file" apart from "decryption failed".
+fn map_container_parse_error(error: crypto::CryptoError) -> AppError {
+    match error {
+        crypto::CryptoError::ChecksumMismatch(msg) => AppError::CorruptFile(msg),
+        other => AppError::CryptoError(other),
+    }
 }
 
 impl Serialize for AppError {
@@ -48,15 +78,15 @@ impl Serialize for AppError {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EncryptRequest {
     pub content: String,
-    pub passphrase: String,
-    pub password: String,
+    pub passphrase: SafePassword,
+    pub password: SafePassword,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DecryptRequest {
     pub encrypted_content: String,
-    pub passphrase: String,
-    pub password: String,
+    pub passphrase: SafePassword,
+    pub password: SafePassword,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -110,6 +140,10 @@ pub struct WalletMetadata {
     pub wallet_type: WalletType,
     pub created_at: DateTime<Utc>,
     pub seed_phrase_word_count: Option<usize>,
+    /// Recovery birthday: a block height or ISO date recorded at encryption
+    /// time, so a later restore can skip scanning the chain before it.
+    #[serde(default)]
+    pub recovery_birthday: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -119,6 +153,8 @@ pub struct WalletInfo {
     pub created_at: DateTime<Utc>,
     pub file_path: Option<String>,
     pub seed_phrase_word_count: Option<usize>,
+    #[serde(default)]
+    pub recovery_birthday: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -143,22 +179,37 @@ pub struct FilenameParseResult {
     pub original_filename: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileIntegrityInfo {
     pub sha256_hash: String,
     pub file_size: u64,
     pub created_at: DateTime<Utc>,
     pub encryption_method: String,
     pub key_derivation: String,
+    #[serde(default)]
+    pub recovery_birthday: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AdvancedEncryptRequest {
     pub content: String,
-    pub passphrase: String,
-    pub password: Option<String>, // Make password optional
-    pub key_derivation_method: Option<String>, // "pbkdf2" or "argon2"
-    pub iterations: Option<u32>,
+    pub passphrase: SafePassword,
+    pub password: Option<SafePassword>, // Make password optional
+    pub key_derivation_method: Option<String>, // "pbkdf2", "argon2", or "scrypt"
+    pub iterations: Option<u32>, // pbkdf2 iteration count
+    #[serde(default)]
+    pub argon2_m_cost_kib: Option<u32>, // Argon2id memory cost, in KiB
+    #[serde(default)]
+    pub argon2_t_cost: Option<u32>, // Argon2id number of passes
+    #[serde(default)]
+    pub argon2_p_cost: Option<u32>, // Argon2id degree of parallelism
+    #[serde(default)]
+    pub scrypt_log_n: Option<u8>, // scrypt CPU/memory cost as log2(N)
+    #[serde(default)]
+    pub scrypt_r: Option<u32>, // scrypt block size
+    #[serde(default)]
+    pub scrypt_p: Option<u32>, // scrypt parallelization
+    pub encryption_algorithm: Option<String>, // "aes256gcm", "aes256gcmsiv", or "chacha20poly1305"
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -180,19 +231,22 @@ pub struct IntegrityVerificationResult {
 // Application state to hold the wordlist
 pub struct AppState {
     pub wordlist: HashSet<String>,
+    pub custom_wordlist: std::sync::Mutex<Option<HashSet<String>>>,
+    /// Five-digit dice code -> word, for `generate_passphrase_from_dice_rolls`.
+    pub dice_map: HashMap<String, String>,
 }
 
 #[tauri::command]
 async fn encrypt_seed_phrase(
-    seed_phrase: String,
-    passphrase: String,
-    password: Option<String>, // Make password optional
+    seed_phrase: SafePassword,
+    passphrase: SafePassword,
+    password: Option<SafePassword>, // Make password optional
     state: State<'_, AppState>,
 ) -> Result<String, AppError> {
 
     // Validate seed phrase first
     let validator = SeedPhraseValidator::new();
-    let seed_phrase_validation = validator.validate_seed_phrase(&seed_phrase);
+    let seed_phrase_validation = validator.validate_seed_phrase(seed_phrase.as_str());
     if !seed_phrase_validation.is_valid {
         return Err(AppError::SeedPhraseError(format!(
             "Seed phrase validation failed: {}",
@@ -201,7 +255,7 @@ async fn encrypt_seed_phrase(
     }
 
     // Validate passphrase
-    let validation = validate_passphrase(&passphrase, &state.wordlist);
+    let validation = validate_passphrase(passphrase.as_str(), &state.wordlist, None);
     if !validation.is_valid {
         return Err(AppError::ValidationError(format!(
             "Invalid passphrase: {}",
@@ -213,7 +267,7 @@ async fn encrypt_seed_phrase(
     let password = password.unwrap_or_default();
 
     // Perform encryption
-    encrypt_data(&seed_phrase, &passphrase, &password)
+    encrypt_data(seed_phrase.as_str(), passphrase.as_str(), password.as_str())
         .map_err(|e| AppError::EncryptionError(e.to_string()))
 }
 
@@ -224,7 +278,7 @@ async fn decrypt_content(
 ) -> Result<String, AppError> {
 
     // Validate passphrase
-    let validation = validate_passphrase(&request.passphrase, &state.wordlist);
+    let validation = validate_passphrase(request.passphrase.as_str(), &state.wordlist, None);
     if !validation.is_valid {
         return Err(AppError::ValidationError(format!(
             "Invalid passphrase: {}",
@@ -232,30 +286,84 @@ async fn decrypt_content(
         )));
     }
 
-    // Use empty string if password is not provided
-    let password = request.password;
-
     // Perform decryption
-    decrypt_data(&request.encrypted_content, &request.passphrase, &password)
+    decrypt_data(&request.encrypted_content, request.passphrase.as_str(), request.password.as_str())
         .map_err(|e| AppError::DecryptionError(e.to_string()))
 }
 
+/// Generates a passphrase either by fixed word count, or (if
+/// `target_entropy_bits` is given) by the minimum word count reaching that
+/// many bits of entropy — e.g. 128 bits, the common floor for protecting a
+/// 24-word seed, without the caller hand-computing how many words that is.
 #[tauri::command]
 async fn generate_passphrase(
     word_count: Option<usize>,
+    wordlist_profile: Option<String>,
+    target_entropy_bits: Option<f64>,
     state: State<'_, AppState>,
-) -> Result<String, AppError> {
+) -> Result<PassphraseStrengthResult, AppError> {
+    let profile = match wordlist_profile.as_deref() {
+        Some("bip39") => WordlistProfile::Bip39,
+        Some("custom") => WordlistProfile::Custom,
+        _ => WordlistProfile::Eff,
+    };
+
+    let custom_wordlist = state.custom_wordlist.lock().unwrap();
+
+    if let Some(target_bits) = target_entropy_bits {
+        return generate_passphrase_for_target_entropy_with_strength(
+            target_bits,
+            profile,
+            &state.wordlist,
+            custom_wordlist.as_ref(),
+            PBKDF2_ITERATIONS,
+        )
+        .map_err(|e| AppError::ValidationError(e.to_string()));
+    }
+
     let count = word_count.unwrap_or(6);
-    generate_diceware_passphrase(count, &state.wordlist)
+    generate_passphrase_with_strength(
+        count,
+        profile,
+        &state.wordlist,
+        custom_wordlist.as_ref(),
+        PBKDF2_ITERATIONS,
+    )
+    .map_err(|e| AppError::ValidationError(e.to_string()))
+}
+
+/// Converts physically rolled dice into a passphrase, for a user who doesn't
+/// trust the machine's RNG. Each entry of `rolls` is five dice results
+/// (1-6) indexing one word in the EFF large wordlist, read the same way a
+/// paranoid diceware user would read it off a printed copy of the list.
+#[tauri::command]
+async fn generate_passphrase_from_dice_rolls(
+    rolls: Vec<[u8; 5]>,
+    state: State<'_, AppState>,
+) -> Result<PassphraseStrengthResult, AppError> {
+    passphrase_from_dice_rolls_with_strength(&rolls, &state.dice_map, PBKDF2_ITERATIONS)
         .map_err(|e| AppError::ValidationError(e.to_string()))
 }
 
+#[tauri::command]
+async fn set_custom_wordlist(words: Vec<String>, state: State<'_, AppState>) -> Result<(), AppError> {
+    if words.is_empty() {
+        return Err(AppError::ValidationError(
+            "Custom wordlist cannot be empty".to_string(),
+        ));
+    }
+    let normalized: HashSet<String> = words.into_iter().map(|word| word.to_lowercase()).collect();
+    *state.custom_wordlist.lock().unwrap() = Some(normalized);
+    Ok(())
+}
+
 #[tauri::command]
 async fn validate_passphrase_words(
     passphrase: String,
+    minimum_entropy_bits: Option<f64>,
     state: State<'_, AppState>,
 ) -> Result<ValidationResult, AppError> {
-    Ok(validate_passphrase(&passphrase, &state.wordlist))
+    Ok(validate_passphrase(&passphrase, &state.wordlist, minimum_entropy_bits))
 }
 
 #[tauri::command]
@@ -280,9 +388,37 @@ async fn save_to_file(_content: String, _filename: String) -> Result<(), AppErro
     Ok(())
 }
 
+/// Hides `ciphertext` (the base64 blob `encrypt_seed_phrase`/
+/// `encrypt_with_advanced_crypto` already produce) inside the least
+/// significant bits of `carrier_image_base64`, a user-supplied PNG. Returns
+/// the stego PNG, base64-encoded, so it can be written out with
+/// `save_to_file` and looks like an ordinary photo.
 #[tauri::command]
-async fn validate_seed_phrase(seed_phrase: String) -> Result<SeedPhraseValidationResult, AppError> {
-    let validator = SeedPhraseValidator::new();
+async fn embed_in_image(carrier_image_base64: String, ciphertext: String) -> Result<String, AppError> {
+    let carrier_png = general_purpose::STANDARD
+        .decode(&carrier_image_base64)
+        .map_err(|e| AppError::ValidationError(format!("Invalid carrier image data: {}", e)))?;
+    let stego_png = steganography::embed_in_image(&carrier_png, &ciphertext)?;
+    Ok(general_purpose::STANDARD.encode(stego_png))
+}
+
+/// Reverses `embed_in_image`, recovering the exact base64 ciphertext string
+/// that `decrypt_content`/`decrypt_with_advanced_crypto` already consume.
+#[tauri::command]
+async fn extract_from_image(stego_image_base64: String) -> Result<String, AppError> {
+    let stego_png = general_purpose::STANDARD
+        .decode(&stego_image_base64)
+        .map_err(|e| AppError::ValidationError(format!("Invalid image data: {}", e)))?;
+    Ok(steganography::extract_from_image(&stego_png)?)
+}
+
+#[tauri::command]
+async fn validate_seed_phrase(
+    seed_phrase: String,
+    language: Option<String>,
+) -> Result<MnemonicValidationResult, AppError> {
+    let language = resolve_language(language.as_deref(), &seed_phrase);
+    let validator = SeedPhraseValidator::with_language(language);
     Ok(validator.validate_seed_phrase(&seed_phrase))
 }
 
@@ -301,17 +437,29 @@ async fn format_seed_phrase(raw_input: String) -> Result<String, AppError> {
 }
 
 #[tauri::command]
-async fn format_seed_phrase_comprehensive(raw_input: String) -> Result<SeedPhraseFormatResult, AppError> {
-    let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(&raw_input);
-    
+async fn format_seed_phrase_comprehensive(
+    raw_input: String,
+    language: Option<String>,
+) -> Result<SeedPhraseFormatResult, AppError> {
+    let language = resolve_language(language.as_deref(), &raw_input);
+    let result = SeedPhraseFormatter::format_seed_phrase_comprehensive_in(&raw_input, language);
+
     // Validate the formatting result
     if let Err(e) = SeedPhraseFormatter::validate_and_confirm_format(&result) {
         return Err(AppError::SeedPhraseError(format!("Formatting validation failed: {}", e)));
     }
-    
+
     Ok(result)
 }
 
+/// Auto-detects which bundled BIP39 wordlist a seed phrase is most likely
+/// written in, so the UI can show the user what was detected and let them
+/// confirm or override it before the phrase is validated or formatted.
+#[tauri::command]
+async fn detect_seed_phrase_language(seed_phrase: String) -> Result<LanguageDetectionResult, AppError> {
+    Ok(bip39_wordlist::detect_seed_phrase_language(&seed_phrase))
+}
+
 #[tauri::command]
 async fn encrypt_seed_phrase_with_wallet_metadata(
     seed_phrase: String,
@@ -332,7 +480,7 @@ async fn encrypt_seed_phrase_with_wallet_metadata(
     }
 
     // Validate passphrase
-    let validation = validate_passphrase(&passphrase, &state.wordlist);
+    let validation = validate_passphrase(&passphrase, &state.wordlist, None);
     if !validation.is_valid {
         return Err(AppError::ValidationError(format!(
             "Invalid passphrase: {}",
@@ -359,6 +507,7 @@ async fn encrypt_seed_phrase_with_wallet_metadata(
             created_at: metadata.created_at,
             file_path: None,
             seed_phrase_word_count: Some(seed_phrase_validation.word_count),
+            recovery_birthday: metadata.recovery_birthday.clone(),
         };
         (filename, Some(wallet_info))
     } else {
@@ -438,14 +587,53 @@ async fn check_enhanced_network_security(has_seed_phrase_content: bool) -> Resul
 }
 
 #[tauri::command]
-async fn get_seed_phrase_suggestions(prefix: String, limit: Option<usize>) -> Result<Vec<String>, AppError> {
+async fn get_seed_phrase_suggestions(
+    prefix: String,
+    limit: Option<usize>,
+    language: Option<String>,
+) -> Result<Vec<String>, AppError> {
     let suggestion_limit = limit.unwrap_or(8);
-    Ok(get_bip39_suggestions(&prefix, suggestion_limit))
+    let language = language.as_deref().and_then(parse_language).unwrap_or(Language::English);
+    Ok(get_bip39_suggestions_in(&prefix, suggestion_limit, language))
+}
+
+#[tauri::command]
+async fn derive_wallet_descriptor(
+    seed_phrase: String,
+    bip39_passphrase: Option<String>,
+    address_count: Option<u32>,
+) -> Result<WalletDescriptor, AppError> {
+    let validator = SeedPhraseValidator::new();
+    let validation = validator.validate_seed_phrase(&seed_phrase);
+    if !validation.is_valid {
+        return Err(AppError::SeedPhraseError(format!(
+            "Seed phrase validation failed: {}",
+            validation.errors.join(", ")
+        )));
+    }
+
+    let passphrase = bip39_passphrase.unwrap_or_default();
+    let count = address_count.unwrap_or(1).max(1);
+
+    Ok(derive_wallet_descriptor_offline(&seed_phrase, &passphrase, count).await?)
 }
 
+/// Suggests corrections for every mistyped word in `phrase`, so a user
+/// fixing a rejected seed phrase gets candidates instead of just a list of
+/// words that didn't match.
 #[tauri::command]
-async fn validate_seed_phrase_word(word: String) -> Result<bool, AppError> {
-    Ok(is_valid_bip39_word(&word))
+async fn suggest_mnemonic_corrections(
+    phrase: String,
+    language: Option<String>,
+) -> Result<Vec<WordSuggestion>, AppError> {
+    let language = language.as_deref().and_then(parse_language).unwrap_or(Language::English);
+    Ok(suggest_corrections_in(&phrase, language))
+}
+
+#[tauri::command]
+async fn validate_seed_phrase_word(word: String, language: Option<String>) -> Result<bool, AppError> {
+    let language = language.as_deref().and_then(parse_language).unwrap_or(Language::English);
+    Ok(is_valid_bip39_word_in(&word, language))
 }
 
 pub fn generate_wallet_filename(metadata: &WalletMetadata) -> String {
@@ -572,6 +760,7 @@ pub fn parse_filename_for_wallet_info(filename: &str) -> FilenameParseResult {
             created_at,
             file_path: Some(filename.to_string()),
             seed_phrase_word_count: word_count,
+            recovery_birthday: None,
         };
         
         FilenameParseResult {
@@ -595,57 +784,92 @@ async fn encrypt_with_advanced_crypto(
 ) -> Result<AdvancedEncryptResult, AppError> {
     use sha2::{Sha256, Digest};
     use rand::RngCore;
-    
+
     // Validate passphrase
-    let validation = validate_passphrase(&request.passphrase, &state.wordlist);
+    let validation = validate_passphrase(request.passphrase.as_str(), &state.wordlist, None);
     if !validation.is_valid {
         return Err(AppError::ValidationError(format!(
             "Invalid passphrase: {}",
             validation.errors.join(", ")
         )));
     }
-    
+
     // Use empty string if password is not provided
     let password = request.password.unwrap_or_default();
-    
+
     // Generate random salt and IV
     let mut salt = [0u8; 32];
     let mut iv = [0u8; 12];
     rand::thread_rng().fill_bytes(&mut salt);
     rand::thread_rng().fill_bytes(&mut iv);
-    
-    // Choose key derivation method
+
+    // Choose the KDF and its cost parameters, defaulting unset knobs the
+    // same way each KDF's dedicated derive function always has.
     let key_derivation_method = request.key_derivation_method
         .unwrap_or_else(|| "pbkdf2".to_string());
-    let iterations = request.iterations.unwrap_or(100000);
-    
-    // Derive key using specified method
-    let key = match key_derivation_method.as_str() {
-        "argon2" => derive_key_argon2(&request.passphrase, &password, &salt, iterations)?,
-        _ => derive_key_pbkdf2(&request.passphrase, &password, &salt, iterations)?,
+    let kdf_params = KdfParams::from_request(
+        &key_derivation_method,
+        request.iterations,
+        request.argon2_m_cost_kib,
+        request.argon2_t_cost,
+        request.argon2_p_cost,
+        request.scrypt_log_n,
+        request.scrypt_r,
+        request.scrypt_p,
+    );
+    let key = kdf_params.derive_key(&request.passphrase, &password, &salt)?;
+
+    // Pad the plaintext to a fixed bucket size so a 12-word and a 24-word
+    // seed phrase produce identically sized ciphertexts on disk.
+    let padded_plaintext = pad_plaintext(request.content.as_bytes());
+
+    // Encrypt content with the requested AEAD cipher (AES-256-GCM-SIV by
+    // default). Both the container header AND the plaintext metadata block
+    // are authenticated as AEAD associated data, so a tampered cipher/KDF id,
+    // cost parameters, salt, iv, or any `FileIntegrityInfo` field (file size,
+    // timestamp, encryption method, key derivation, recovery birthday) is
+    // caught by the AEAD tag rather than only by the (separately
+    // unauthenticated) header checksum. The metadata's own `sha256_hash`
+    // field is the one exception: it can't be authenticated this way, since
+    // it embeds the ciphertext's own hash, which doesn't exist until after
+    // this call. It's cross-checked directly against the ciphertext on
+    // decrypt instead (see `verify_file_integrity`).
+    let cipher = CipherAlgorithm::from_user_input(request.encryption_algorithm.as_deref());
+    let fingerprint = compute_fingerprint(key.as_bytes());
+
+    // Every field but the hash is known before encryption even runs, since
+    // all supported ciphers use a fixed-size AEAD tag.
+    let mut integrity_info = FileIntegrityInfo {
+        sha256_hash: String::new(),
+        file_size: (padded_plaintext.len() + AEAD_TAG_SIZE) as u64,
+        created_at: Utc::now(),
+        encryption_method: cipher.method_name().to_string(),
+        key_derivation: kdf_params.descriptor(),
+        recovery_birthday: None,
     };
-    
-    // Encrypt content with AES-256-GCM
-    let encrypted_content = encrypt_data_advanced(&request.content, &key, &iv)
+
+    let mut aad = build_container_header(&salt, &iv, cipher, kdf_params, &fingerprint, true);
+    aad.extend_from_slice(&metadata_aad_bytes(&integrity_info).map_err(|e| AppError::EncryptionError(e.to_string()))?);
+    let encrypted_content = encrypt_data_advanced(&padded_plaintext, key.as_bytes(), &iv, cipher, &aad)
         .map_err(|e| AppError::EncryptionError(e.to_string()))?;
-    
+
     // Calculate SHA256 hash of encrypted content
     let mut hasher = Sha256::new();
     hasher.update(&encrypted_content);
-    let hash = format!("{:x}", hasher.finalize());
-    
-    // Create integrity info
-    let integrity_info = FileIntegrityInfo {
-        sha256_hash: hash,
-        file_size: encrypted_content.len() as u64,
-        created_at: Utc::now(),
-        encryption_method: "AES-256-GCM".to_string(),
-        key_derivation: format!("{}-{}", key_derivation_method, iterations),
-    };
-    
+    integrity_info.sha256_hash = format!("{:x}", hasher.finalize());
+
     // Encode final result
-    let final_content = encode_encrypted_with_metadata(&encrypted_content, &salt, &iv, &integrity_info)
-        .map_err(|e| AppError::EncryptionError(e.to_string()))?;
+    let final_content = encode_encrypted_with_metadata(
+        &encrypted_content,
+        &salt,
+        &iv,
+        cipher,
+        kdf_params,
+        &fingerprint,
+        true, // padded
+        &integrity_info,
+    )
+    .map_err(|e| AppError::EncryptionError(e.to_string()))?;
     
     Ok(AdvancedEncryptResult {
         encrypted_content: final_content,
@@ -660,16 +884,17 @@ async fn verify_file_integrity(
     encrypted_content: String,
 ) -> Result<IntegrityVerificationResult, AppError> {
     use sha2::{Sha256, Digest};
-    
+
     // Parse the encrypted content to extract metadata
-    let (content_bytes, integrity_info) = parse_encrypted_with_metadata(&encrypted_content)
-        .map_err(|e| AppError::ValidationError(e.to_string()))?;
-    
+    let container = parse_encrypted_with_metadata(&encrypted_content)
+        .map_err(map_container_parse_error)?;
+    let integrity_info = container.integrity_info;
+
     // Calculate actual hash
     let mut hasher = Sha256::new();
-    hasher.update(&content_bytes);
+    hasher.update(&container.encrypted_data);
     let actual_hash = format!("{:x}", hasher.finalize());
-    
+
     let is_valid = actual_hash == integrity_info.sha256_hash;
     let message = if is_valid {
         "File integrity verified successfully".to_string()
@@ -689,10 +914,10 @@ async fn verify_file_integrity(
 async fn get_file_integrity_info(
     encrypted_content: String,
 ) -> Result<FileIntegrityInfo, AppError> {
-    let (_, integrity_info) = parse_encrypted_with_metadata(&encrypted_content)
-        .map_err(|e| AppError::ValidationError(e.to_string()))?;
-    
-    Ok(integrity_info)
+    let container = parse_encrypted_with_metadata(&encrypted_content)
+        .map_err(map_container_parse_error)?;
+
+    Ok(container.integrity_info)
 }
 
 #[tauri::command]
@@ -701,63 +926,53 @@ async fn decrypt_with_advanced_crypto(
     state: State<'_, AppState>,
 ) -> Result<String, AppError> {
     use sha2::{Sha256, Digest};
-    
+
     // Validate passphrase
-    let validation = validate_passphrase(&request.passphrase, &state.wordlist);
+    let validation = validate_passphrase(request.passphrase.as_str(), &state.wordlist, None);
     if !validation.is_valid {
         return Err(AppError::ValidationError(format!(
             "Invalid passphrase: {}",
             validation.errors.join(", ")
         )));
     }
-    
-    // Use empty string if password is not provided
+
     let password = request.password;
-    
-    // Parse the encrypted content to extract metadata and encrypted data
-    let (encrypted_data, integrity_info) = parse_encrypted_with_metadata(&request.encrypted_content)
-        .map_err(|e| AppError::DecryptionError(e.to_string()))?;
-    
+
+    // Parse the encrypted content to extract the header, metadata, and ciphertext
+    let container = parse_encrypted_with_metadata(&request.encrypted_content)
+        .map_err(map_container_parse_error)?;
+    let DecodedContainer { encrypted_data, salt, iv, cipher, kdf_params, padded, integrity_info, aad, fingerprint: _ } = container;
+
     // Verify file integrity before decryption
     let mut hasher = Sha256::new();
     hasher.update(&encrypted_data);
     let actual_hash = format!("{:x}", hasher.finalize());
-    
+
     if actual_hash != integrity_info.sha256_hash {
         return Err(AppError::DecryptionError(
             "File integrity verification failed - file may be corrupted or tampered with".to_string()
         ));
     }
-    
-    // Extract salt and IV from the metadata (they're in the encrypted data structure)
-    let data = general_purpose::STANDARD.decode(&request.encrypted_content)
-        .map_err(|e| AppError::DecryptionError(format!("Invalid base64 data: {}", e)))?;
-    
-    // Extract salt (32 bytes starting at position 12) and IV (12 bytes starting at position 44)
-    let salt = &data[12..44];
-    let iv = &data[44..56];
-    
-    // Determine key derivation method from metadata
-    let key_derivation_parts: Vec<&str> = integrity_info.key_derivation.split('-').collect();
-    let key_derivation_method = key_derivation_parts.get(0).unwrap_or(&"pbkdf2");
-    let iterations: u32 = key_derivation_parts.get(1)
-        .and_then(|s| s.parse().ok())
-        .unwrap_or(100000);
-    
-    // Derive key using the same method used for encryption
-    let key = match *key_derivation_method {
-        "argon2" => derive_key_argon2(&request.passphrase, &password, salt, iterations)?,
-        _ => derive_key_pbkdf2(&request.passphrase, &password, salt, iterations)?,
+
+    // Derive the key using the exact KDF and cost parameters read straight
+    // from the header.
+    let key = kdf_params.derive_key(&request.passphrase, &password, &salt)?;
+
+    // Decrypt using whichever cipher the header says the container was
+    // encrypted with, re-authenticating the same associated data the
+    // container was originally encrypted under (header+metadata bytes for
+    // AESADV06, header bytes alone for AESADV03-05, or empty for legacy
+    // pre-AESADV03 containers, which predate AAD support).
+    let plaintext = decrypt_data_advanced(&encrypted_data, key.as_bytes(), &iv, cipher, &aad)
+        .map_err(|e| AppError::DecryptionError(e.to_string()))?;
+
+    // Strip the length-hiding padding, if the header says it's present
+    let plaintext = if padded {
+        unpad_plaintext(&plaintext).map_err(|e| AppError::DecryptionError(e.to_string()))?
+    } else {
+        plaintext
     };
-    
-    // Decrypt the data
-    let cipher = aes_gcm::Aes256Gcm::new_from_slice(&key)
-        .map_err(|e| AppError::DecryptionError(format!("Failed to create cipher: {}", e)))?;
-    let nonce = aes_gcm::Nonce::from_slice(iv);
-    
-    let plaintext = cipher.decrypt(nonce, encrypted_data.as_slice())
-        .map_err(|e| AppError::DecryptionError(format!("Decryption failed: {}", e)))?;
-    
+
     // Convert to string
     String::from_utf8(plaintext)
         .map_err(|e| AppError::DecryptionError(format!("Invalid UTF-8 data: {}", e)))
@@ -786,6 +1001,135 @@ async fn export_integrity_hash(
     Ok(export_data)
 }
 
+/// Wraps an encrypted container in copy-paste-safe ASCII armor (PGP/
+/// Bech32m-style: header lines, Base85 body, trailing CRC-24 checksum line)
+/// so a backup survives email and paper round-trips.
+#[tauri::command]
+async fn export_ascii_armor(encoded_container: String) -> Result<String, AppError> {
+    Ok(encode_ascii_armor(&encoded_container)?)
+}
+
+/// Reverses `export_ascii_armor`: strips the armor, verifies the CRC-24
+/// checksum, and returns the base64 container string that
+/// `decrypt_content`/`decrypt_with_advanced_crypto` already consume.
+#[tauri::command]
+async fn import_ascii_armor(armored: String) -> Result<String, AppError> {
+    Ok(decode_ascii_armor(&armored)?)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FingerprintCheckResult {
+    /// `false` for containers older than AESADV06, which never stored a
+    /// fingerprint; `matches` is meaningless in that case.
+    pub fingerprint_available: bool,
+    pub matches: bool,
+}
+
+/// Checks whether `passphrase`/`password` are the ones `encrypted_content`
+/// was encrypted with, without running a full (and for Argon2id/scrypt,
+/// memory-heavy) decryption attempt. Lets a user with many backup files find
+/// the one a candidate secret opens by re-deriving the key and comparing its
+/// non-secret fingerprint against the one stored in the header.
+#[tauri::command]
+async fn check_fingerprint_match(
+    encrypted_content: String,
+    passphrase: SafePassword,
+    password: Option<SafePassword>,
+) -> Result<FingerprintCheckResult, AppError> {
+    let container = parse_encrypted_with_metadata(&encrypted_content)
+        .map_err(map_container_parse_error)?;
+
+    let Some(stored_fingerprint) = container.fingerprint else {
+        return Ok(FingerprintCheckResult {
+            fingerprint_available: false,
+            matches: false,
+        });
+    };
+
+    let password = password.unwrap_or_default();
+    let candidate = fingerprint_of(container.kdf_params, &passphrase, &password, &container.salt)?;
+    let matches = fingerprint_matches(&fingerprint_hex(&stored_fingerprint), &candidate);
+
+    Ok(FingerprintCheckResult {
+        fingerprint_available: true,
+        matches,
+    })
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RecipientKeypair {
+    pub public_key: String,
+    pub private_key: String,
+}
+
+/// Explicit-trust mode: generates a fresh random X25519 keypair. The caller
+/// is responsible for storing `private_key` securely and sharing
+/// `public_key` with whoever should be able to decrypt to this identity.
+#[tauri::command]
+async fn generate_recipient_keypair() -> Result<RecipientKeypair, AppError> {
+    let keypair = X25519KeyPair::generate();
+    Ok(RecipientKeypair {
+        public_key: general_purpose::STANDARD.encode(keypair.public_key_bytes()),
+        private_key: general_purpose::STANDARD.encode(keypair.private_key_bytes()),
+    })
+}
+
+/// Shared-secret mode: deterministically derives the same keypair from
+/// `passphrase` every time, so every holder of the passphrase only needs to
+/// know their own derived public key to be a valid recipient — there's
+/// nothing else to distribute or store.
+#[tauri::command]
+async fn derive_recipient_keypair_from_passphrase(
+    passphrase: SafePassword,
+) -> Result<RecipientKeypair, AppError> {
+    let keypair = X25519KeyPair::from_passphrase(&passphrase);
+    Ok(RecipientKeypair {
+        public_key: general_purpose::STANDARD.encode(keypair.public_key_bytes()),
+        private_key: general_purpose::STANDARD.encode(keypair.private_key_bytes()),
+    })
+}
+
+fn decode_recipient_key(encoded: &str, field: &str) -> Result<[u8; 32], AppError> {
+    let bytes = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| AppError::ValidationError(format!("Invalid {}: {}", field, e)))?;
+    bytes
+        .try_into()
+        .map_err(|_| AppError::ValidationError(format!("{} must be 32 bytes", field)))
+}
+
+/// Encrypts `mnemonic` so that any one holder of a private key matching one
+/// of `recipient_public_keys` (base64 X25519 public keys, from
+/// `generate_recipient_keypair` or `derive_recipient_keypair_from_passphrase`)
+/// can recover it via `decrypt_mnemonic_with_recipient_key`.
+#[tauri::command]
+async fn encrypt_mnemonic_for_recipients(
+    mnemonic: SafePassword,
+    recipient_public_keys: Vec<String>,
+) -> Result<MultiRecipientContainer, AppError> {
+    let keys = recipient_public_keys
+        .iter()
+        .map(|encoded| decode_recipient_key(encoded, "recipient public key"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(encrypt_for_recipients(mnemonic.as_str().as_bytes(), &keys)?)
+}
+
+/// Reverses `encrypt_mnemonic_for_recipients`: tries `private_key` against
+/// every wrapped-key entry in `container` until one unwraps.
+#[tauri::command]
+async fn decrypt_mnemonic_with_recipient_key(
+    container: MultiRecipientContainer,
+    private_key: String,
+) -> Result<String, AppError> {
+    let key = decode_recipient_key(&private_key, "private key")?;
+    let keypair = X25519KeyPair::from_private_key_bytes(key);
+    let plaintext = decrypt_with_private_key(&container, &keypair)?;
+
+    String::from_utf8(plaintext)
+        .map_err(|e| AppError::DecryptionError(format!("Recovered plaintext was not valid UTF-8: {}", e)))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Load EFF wordlist
@@ -793,8 +1137,16 @@ pub fn run() {
         eprintln!("Warning: Failed to load EFF wordlist: {}", e);
         HashSet::new()
     });
+    let dice_map = load_eff_dice_map().unwrap_or_else(|e| {
+        eprintln!("Warning: Failed to load EFF dice map: {}", e);
+        HashMap::new()
+    });
 
-    let app_state = AppState { wordlist };
+    let app_state = AppState {
+        wordlist,
+        custom_wordlist: std::sync::Mutex::new(None),
+        dice_map,
+    };
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
@@ -806,9 +1158,13 @@ pub fn run() {
             decrypt_content,
             decrypt_with_advanced_crypto,
             generate_passphrase,
+            generate_passphrase_from_dice_rolls,
+            set_custom_wordlist,
             validate_passphrase_words,
             check_network_status,
             save_to_file,
+            embed_in_image,
+            extract_from_image,
             validate_seed_phrase,
             format_seed_phrase,
             format_seed_phrase_comprehensive,
@@ -819,11 +1175,21 @@ pub fn run() {
             get_security_reminder_config,
             check_enhanced_network_security,
             get_seed_phrase_suggestions,
+            suggest_mnemonic_corrections,
             validate_seed_phrase_word,
+            detect_seed_phrase_language,
             encrypt_with_advanced_crypto,
             verify_file_integrity,
             get_file_integrity_info,
-            export_integrity_hash
+            export_integrity_hash,
+            export_ascii_armor,
+            import_ascii_armor,
+            check_fingerprint_match,
+            generate_recipient_keypair,
+            derive_recipient_keypair_from_passphrase,
+            encrypt_mnemonic_for_recipients,
+            decrypt_mnemonic_with_recipient_key,
+            derive_wallet_descriptor
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,7 +1,11 @@
-use bip39::{Language, Mnemonic};
+use bip39::Language;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use sha2::{Digest, Sha256};
 use thiserror::Error;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::bip39_wordlist::{detect_language, is_valid_bip39_word_in, language_name};
 
 #[derive(Error, Debug)]
 pub enum MnemonicError {
@@ -19,6 +23,18 @@ pub enum MnemonicError {
     
     #[error("BIP39 validation error: {0}")]
     Bip39Error(String),
+
+    #[error("Invalid entropy byte length: expected 16-32 bytes divisible by 4, got {0}")]
+    InvalidByteLength(usize),
+
+    #[error("Word not found in wordlist: {word}")]
+    WordNotFound {
+        word: String,
+        /// Up to three nearest wordlist entries by bounded Levenshtein
+        /// distance (threshold 2), closest first, for a caller to prompt
+        /// the user with instead of a bare failure.
+        suggestions: Vec<String>,
+    },
 }
 
 impl Serialize for MnemonicError {
@@ -37,17 +53,210 @@ pub struct MnemonicValidationResult {
     pub invalid_words: Vec<String>,
     pub errors: Vec<String>,
     pub checksum_valid: bool,
+    /// Which BIP39 wordlist `find_invalid_words`/the checksum check ran
+    /// against: the language pinned via `with_language`, or (for `new()`)
+    /// whatever `detect_language` scored highest. `None` only for an empty
+    /// phrase, where no detection was attempted.
+    pub detected_language: Option<String>,
+    /// One `WordSuggestion` per entry in `invalid_words`, populated
+    /// whenever invalid words are present (see `suggest_corrections_in`).
+    pub corrections: Vec<WordSuggestion>,
+}
+
+/// How `suggest_corrections_in` found its candidates for a mistyped word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrectionStrategy {
+    /// Matched on the token's first four characters, which (per the BIP39
+    /// spec) uniquely identifies a word in every official wordlist.
+    Prefix,
+    /// No prefix match; fell back to Levenshtein edit distance ≤ 2.
+    Levenshtein,
+    /// Neither strategy found a candidate within range.
+    NoMatch,
+}
+
+/// Candidate corrections for one token that didn't match the active
+/// wordlist, ranked best-first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WordSuggestion {
+    pub original: String,
+    pub candidates: Vec<String>,
+    pub strategy: CorrectionStrategy,
+}
+
+/// Suggests corrections for every word in `phrase` that doesn't match
+/// `language`'s wordlist, against the English wordlist. See
+/// `suggest_corrections_in` for the language-aware version.
+pub fn suggest_corrections(phrase: &str) -> Vec<WordSuggestion> {
+    suggest_corrections_in(phrase, Language::English)
+}
+
+/// Suggests corrections for every word in `phrase` that doesn't match
+/// `language`'s wordlist: first by matching the token's first four
+/// characters (which BIP39 guarantees is enough to identify a word),
+/// falling back to Levenshtein edit distance (keeping every word within
+/// distance 2, closest first) when no prefix matches.
+pub fn suggest_corrections_in(phrase: &str, language: Language) -> Vec<WordSuggestion> {
+    let wordlist = language.word_list();
+    phrase
+        .split_whitespace()
+        .filter(|word| !wordlist.contains(word))
+        .map(|word| suggest_word_correction(word, language))
+        .collect()
+}
+
+fn suggest_word_correction(word: &str, language: Language) -> WordSuggestion {
+    let prefix: String = word.chars().take(4).collect();
+    let prefix_matches: Vec<String> = language
+        .word_list()
+        .iter()
+        .filter(|candidate| candidate.starts_with(prefix.as_str()))
+        .map(|candidate| candidate.to_string())
+        .collect();
+
+    if !prefix_matches.is_empty() {
+        return WordSuggestion {
+            original: word.to_string(),
+            candidates: prefix_matches,
+            strategy: CorrectionStrategy::Prefix,
+        };
+    }
+
+    const MAX_EDIT_DISTANCE: usize = 2;
+    let mut by_distance: Vec<(usize, &str)> = language
+        .word_list()
+        .iter()
+        .filter_map(|candidate| {
+            let distance = levenshtein_distance(word, candidate);
+            (distance <= MAX_EDIT_DISTANCE).then_some((distance, *candidate))
+        })
+        .collect();
+    by_distance.sort_by_key(|(distance, _)| *distance);
+
+    let candidates: Vec<String> = by_distance.into_iter().map(|(_, word)| word.to_string()).collect();
+    let strategy = if candidates.is_empty() {
+        CorrectionStrategy::NoMatch
+    } else {
+        CorrectionStrategy::Levenshtein
+    };
+
+    WordSuggestion {
+        original: word.to_string(),
+        candidates,
+        strategy,
+    }
+}
+
+/// Classic DP edit-distance table over the two strings' characters (not
+/// bytes, so accented wordlists compare correctly).
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in dp.iter_mut().enumerate().take(n + 1) {
+        row[0] = i;
+    }
+    for j in 0..=m {
+        dp[0][j] = j;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            dp[i][j] = (dp[i - 1][j] + 1).min(dp[i][j - 1] + 1).min(dp[i - 1][j - 1] + cost);
+        }
+    }
+
+    dp[n][m]
+}
+
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+const MAX_SUGGESTIONS: usize = 3;
+
+/// How many leading characters of each payload word feed Monero's
+/// checksum-word CRC32, per the English mnemonic wordlist convention.
+const MONERO_CHECKSUM_PREFIX_LEN: usize = 3;
+
+/// Standard CRC-32 (IEEE 802.3, polynomial 0xEDB88320), the same variant
+/// Monero uses for its mnemonic checksum word.
+fn crc32_ieee(data: &[u8]) -> u32 {
+    const POLY: u32 = 0xEDB88320;
+    let mut crc = 0xFFFFFFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ POLY } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Same classic DP edit-distance table as `levenshtein_distance`, but
+/// abandons the comparison as soon as every entry in the current row
+/// exceeds `max_distance` - every later row can only grow from there, so
+/// the true distance must exceed it too. Returns `None` in that case
+/// instead of the exact count, letting callers skip far-away wordlist
+/// entries without paying for the full table.
+fn levenshtein_distance_bounded(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut prev_row: Vec<usize> = (0..=m).collect();
+    for i in 1..=n {
+        let mut row = vec![0usize; m + 1];
+        row[0] = i;
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            row[j] = (prev_row[j] + 1).min(row[j - 1] + 1).min(prev_row[j - 1] + cost);
+        }
+        if row.iter().min().copied().unwrap_or(0) > max_distance {
+            return None;
+        }
+        prev_row = row;
+    }
+
+    let distance = prev_row[m];
+    (distance <= max_distance).then_some(distance)
+}
+
+/// Finds up to `limit` wordlist entries nearest `word` by bounded
+/// Levenshtein distance, sorted by distance then lexicographically.
+fn nearest_wordlist_matches(word: &str, wordlist: &[&str], max_distance: usize, limit: usize) -> Vec<String> {
+    let mut matches: Vec<(usize, &str)> = wordlist
+        .iter()
+        .filter_map(|candidate| levenshtein_distance_bounded(word, candidate, max_distance).map(|distance| (distance, *candidate)))
+        .collect();
+    matches.sort_by(|(distance_a, word_a), (distance_b, word_b)| distance_a.cmp(distance_b).then_with(|| word_a.cmp(word_b)));
+    matches.into_iter().take(limit).map(|(_, word)| word.to_string()).collect()
 }
 
 pub struct SeedPhraseValidator {
-    language: Language,
+    /// `None` means auto-detect: `validate_mnemonic` scores the phrase
+    /// against every bundled wordlist via `detect_language` and uses the
+    /// best match, falling back to English only when no wordlist matches.
+    language: Option<Language>,
 }
 
 impl SeedPhraseValidator {
     pub fn new() -> Self {
-        Self {
-            language: Language::English,
-        }
+        Self { language: None }
+    }
+
+    /// Builds a validator pinned to a specific BIP39 wordlist, for callers
+    /// that already know (or have already detected) the mnemonic's language.
+    pub fn with_language(language: Language) -> Self {
+        Self { language: Some(language) }
+    }
+
+    /// Validates a seed phrase, auto-detecting its language when the caller
+    /// didn't pin one via `with_language`. This is the entry point the
+    /// Tauri commands use.
+    pub fn validate_seed_phrase(&self, mnemonic_str: &str) -> MnemonicValidationResult {
+        self.validate_mnemonic(mnemonic_str)
     }
 
     /// Validates a BIP39 mnemonic phrase
@@ -59,12 +268,23 @@ impl SeedPhraseValidator {
                 invalid_words: vec![],
                 errors: vec!["Empty seed phrase provided".to_string()],
                 checksum_valid: false,
+                detected_language: None,
+                corrections: vec![],
             };
         }
 
         let words: Vec<&str> = mnemonic_str.trim().split_whitespace().collect();
         let word_count = words.len();
 
+        // A pinned language is used as-is; otherwise score the phrase
+        // against every bundled wordlist and use whichever matches best, so
+        // a valid Japanese/Spanish/French/... phrase isn't flagged as
+        // invalid just because it isn't English.
+        let language = self
+            .language
+            .unwrap_or_else(|| detect_language(mnemonic_str).unwrap_or(Language::English));
+        let detected_language = Some(language_name(language).to_string());
+
         // Check word count (BIP39 supports 12, 15, 18, 21, 24 words)
         if !self.validate_word_count(word_count) {
             return MnemonicValidationResult {
@@ -73,48 +293,110 @@ impl SeedPhraseValidator {
                 invalid_words: vec![],
                 errors: vec![format!("Invalid word count: expected 12, 15, 18, 21, or 24 words, got {}", word_count)],
                 checksum_valid: false,
+                detected_language,
+                corrections: vec![],
             };
         }
 
         // Check for invalid words
-        let invalid_words = self.find_invalid_words(&words);
+        let invalid_words = self.find_invalid_words(&words, language);
         if !invalid_words.is_empty() {
+            let corrections = invalid_words
+                .iter()
+                .map(|word| suggest_word_correction(word, language))
+                .collect();
             return MnemonicValidationResult {
                 is_valid: false,
                 word_count,
                 invalid_words: invalid_words.clone(),
                 errors: vec![format!("Invalid words found: {:?}", invalid_words)],
                 checksum_valid: false,
+                detected_language,
+                corrections,
             };
         }
 
-        // Validate checksum using bip39 crate
-        match Mnemonic::from_str(mnemonic_str) {
-            Ok(_) => MnemonicValidationResult {
+        // Validate the BIP39 checksum bit-by-bit so we can point at the
+        // specific failure instead of only reporting that the crate rejected it
+        match self.verify_checksum(&words, language) {
+            Ok(()) => MnemonicValidationResult {
                 is_valid: true,
                 word_count,
                 invalid_words: vec![],
                 errors: vec![],
                 checksum_valid: true,
+                detected_language,
+                corrections: vec![],
             },
             Err(e) => MnemonicValidationResult {
                 is_valid: false,
                 word_count,
                 invalid_words: vec![],
-                errors: vec![format!("BIP39 validation failed: {}", e)],
+                errors: vec![e],
                 checksum_valid: false,
+                detected_language,
+                corrections: vec![],
             },
         }
     }
 
+    /// Verifies the BIP39 checksum embedded in the final bits of the phrase.
+    ///
+    /// Each word contributes 11 bits (its index in the wordlist), so the full
+    /// phrase is a bitstream of `11 * word_count` bits, big-endian, split into
+    /// `ENT` entropy bits followed by `CS = ENT / 32` checksum bits, where
+    /// `ENT = word_count * 11 - word_count * 11 / 33`. The checksum must equal
+    /// the leading `CS` bits of SHA-256(entropy bytes).
+    fn verify_checksum(&self, words: &[&str], language: Language) -> Result<(), String> {
+        let wordlist = language.word_list();
+        let total_bits = words.len() * 11;
+        let cs_bits = total_bits / 33;
+        let ent_bits = total_bits - cs_bits;
+
+        let mut bits: Vec<bool> = Vec::with_capacity(total_bits);
+        for word in words {
+            let index = wordlist
+                .iter()
+                .position(|w| w == word)
+                .ok_or_else(|| format!("'{}' is not in the wordlist", word))?;
+            for bit in (0..11).rev() {
+                bits.push((index >> bit) & 1 == 1);
+            }
+        }
+
+        // ENT is always a whole number of bytes for the supported word counts.
+        let mut entropy = vec![0u8; ent_bits / 8];
+        for (i, bit) in bits[..ent_bits].iter().enumerate() {
+            if *bit {
+                entropy[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&entropy);
+        let hash = hasher.finalize();
+
+        // The checksum isn't byte-aligned, so compare it bit by bit rather
+        // than as a byte slice.
+        for i in 0..cs_bits {
+            let phrase_bit = bits[ent_bits + i];
+            let hash_bit = (hash[i / 8] >> (7 - (i % 8))) & 1 == 1;
+            if phrase_bit != hash_bit {
+                return Err("last word fails BIP39 checksum".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validates the word count for BIP39 mnemonic
     pub fn validate_word_count(&self, word_count: usize) -> bool {
         matches!(word_count, 12 | 15 | 18 | 21 | 24)
     }
 
     /// Finds invalid words in the mnemonic
-    fn find_invalid_words(&self, words: &[&str]) -> Vec<String> {
-        let wordlist = self.language.word_list();
+    fn find_invalid_words(&self, words: &[&str], language: Language) -> Vec<String> {
+        let wordlist = language.word_list();
         words
             .iter()
             .filter(|word| !wordlist.contains(word))
@@ -124,7 +406,110 @@ impl SeedPhraseValidator {
 
     /// Checks if the checksum is valid
     pub fn check_checksum(&self, mnemonic_str: &str) -> bool {
-        Mnemonic::from_str(mnemonic_str).is_ok()
+        let words: Vec<&str> = mnemonic_str.trim().split_whitespace().collect();
+        let language = self
+            .language
+            .unwrap_or_else(|| detect_language(mnemonic_str).unwrap_or(Language::English));
+        self.verify_checksum(&words, language).is_ok()
+    }
+}
+
+/// A single share that passed `MnemonicSetValidator::validate_set`:
+/// its expected word count and checksum both checked out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mnemonic {
+    pub phrase: String,
+    pub word_count: usize,
+    pub language: String,
+}
+
+/// Validates a multi-share recovery bundle in one pass, e.g. a 9-word
+/// nonce plus a 24-word payload, or several SLIP39-style shares: each
+/// phrase is checked against its own expected word count and BIP39
+/// checksum via `SeedPhraseValidator`, instead of a caller validating
+/// shares one at a time.
+pub struct MnemonicSetValidator {
+    expected_word_counts: Vec<usize>,
+}
+
+impl MnemonicSetValidator {
+    /// `expected_word_counts[i]` is the word count `validate_set` requires
+    /// of `phrases[i]`, e.g. `vec![9, 24]` for a nonce-plus-payload bundle.
+    pub fn new(expected_word_counts: Vec<usize>) -> Self {
+        Self { expected_word_counts }
+    }
+
+    /// Validates `phrases` positionally against the configured word
+    /// counts. Returns every parsed `Mnemonic` if all shares pass, or the
+    /// full set of per-share `MnemonicValidationResult`s (so a caller can
+    /// tell exactly which share(s) failed and why) otherwise.
+    pub fn validate_set(&self, phrases: &[&str]) -> Result<Vec<Mnemonic>, Vec<MnemonicValidationResult>> {
+        if phrases.len() != self.expected_word_counts.len() {
+            let errors = phrases
+                .iter()
+                .map(|phrase| {
+                    let mut result = SeedPhraseValidator::new().validate_mnemonic(phrase);
+                    result.is_valid = false;
+                    result.errors.push(format!(
+                        "Expected {} shares in this bundle, got {}",
+                        self.expected_word_counts.len(),
+                        phrases.len()
+                    ));
+                    result
+                })
+                .collect();
+            return Err(errors);
+        }
+
+        let validator = SeedPhraseValidator::new();
+        let results: Vec<MnemonicValidationResult> = phrases
+            .iter()
+            .zip(&self.expected_word_counts)
+            .map(|(phrase, &expected_word_count)| {
+                let mut result = validator.validate_mnemonic(phrase);
+                if result.word_count != expected_word_count {
+                    result.is_valid = false;
+                    result
+                        .errors
+                        .push(format!("Expected {} words for this share, got {}", expected_word_count, result.word_count));
+                }
+                result
+            })
+            .collect();
+
+        if results.iter().all(|result| result.is_valid) {
+            Ok(phrases
+                .iter()
+                .zip(&results)
+                .map(|(phrase, result)| Mnemonic {
+                    phrase: phrase.trim().to_string(),
+                    word_count: result.word_count,
+                    language: result.detected_language.clone().unwrap_or_else(|| "english".to_string()),
+                })
+                .collect())
+        } else {
+            Err(results)
+        }
+    }
+}
+
+/// Which checksum a mnemonic's length implies: the SHA-256 scheme BIP39
+/// uses for 12/15/18/21/24 words, or the CRC32-over-word-prefixes scheme
+/// Monero's legacy (13-word) and standard (25-word) seeds use instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumScheme {
+    Bip39Sha256,
+    MoneroCrc32,
+}
+
+/// The word count families `ChecksumScheme` dispatches on; any other count
+/// matches neither.
+fn checksum_scheme_for_word_count(word_count: usize) -> Option<ChecksumScheme> {
+    match word_count {
+        12 | 15 | 18 | 21 | 24 => Some(ChecksumScheme::Bip39Sha256),
+        13 | 25 => Some(ChecksumScheme::MoneroCrc32),
+        _ => None,
     }
 }
 
@@ -135,13 +520,32 @@ pub struct SeedPhraseFormatResult {
     pub formatted_word_count: usize,
     pub changes_made: Vec<String>,
     pub is_valid_format: bool,
+    /// The wordlist `format_seed_phrase_comprehensive_in` formatted against.
+    pub detected_language: String,
+    /// Whether NFKD normalization (step 2) actually altered any codepoints.
+    pub nfkd_normalized: bool,
+    /// Which checksum scheme `formatted_word_count` implies, or `None` if
+    /// it matches neither the BIP39 nor the Monero length family.
+    pub checksum_scheme: Option<ChecksumScheme>,
 }
 
-pub struct MnemonicFormatter;
+pub struct SeedPhraseFormatter;
+
+impl SeedPhraseFormatter {
+    /// Comprehensive mnemonic input cleaning and formatting, against the
+    /// English wordlist. See `format_seed_phrase_comprehensive_in` for the
+    /// language-aware version non-English seed phrases need.
+    pub fn format_seed_phrase_comprehensive(raw_input: &str) -> SeedPhraseFormatResult {
+        Self::format_seed_phrase_comprehensive_in(raw_input, Language::English)
+    }
 
-impl MnemonicFormatter {
-    /// Comprehensive mnemonic input cleaning and formatting
-    pub fn format_mnemonic_comprehensive(raw_input: &str) -> SeedPhraseFormatResult {
+    /// Comprehensive mnemonic input cleaning and formatting against
+    /// `language`'s wordlist. NFKD-normalizes before sanitizing so accented
+    /// wordlists (Spanish, French, Italian, Portuguese, Czech) compare
+    /// correctly regardless of whether the input arrived precomposed or
+    /// already decomposed, and keeps combining marks during sanitization
+    /// instead of stripping them as "non-alphabetic".
+    pub fn format_seed_phrase_comprehensive_in(raw_input: &str, language: Language) -> SeedPhraseFormatResult {
         let mut changes_made = Vec::new();
         let original_words: Vec<&str> = raw_input.split_whitespace().collect();
         let original_word_count = original_words.len();
@@ -154,24 +558,35 @@ impl MnemonicFormatter {
             .filter(|word| !word.is_empty())
             .collect::<Vec<&str>>()
             .join(" ");
-        
+
         if whitespace_cleaned != raw_input.trim() {
             changes_made.push("Removed extra whitespace and normalized spacing".to_string());
         }
 
-        // Step 2: Check if case normalization is needed
-        let case_normalized = Self::normalize_case(&whitespace_cleaned);
-        if case_normalized != whitespace_cleaned {
+        // Step 2: NFKD-normalize to the form the BIP39 wordlists themselves
+        // are bundled in, so composed and decomposed forms of the same word
+        // (and of the same phrase typed on different input methods) collapse
+        // to the canonical wordlist form before any other step compares them
+        let unicode_normalized: String = whitespace_cleaned.nfkd().collect();
+        let nfkd_normalized = unicode_normalized != whitespace_cleaned;
+        if nfkd_normalized {
+            changes_made.push("Applied NFKD normalization".to_string());
+        }
+
+        // Step 3: Check if case normalization is needed
+        let case_normalized = Self::normalize_case(&unicode_normalized);
+        if case_normalized != unicode_normalized {
             changes_made.push("Converted to lowercase".to_string());
         }
 
-        // Step 3: Remove any non-alphabetic characters (except spaces)
+        // Step 4: Remove stray characters, keeping combining marks so
+        // decomposed accents (e.g. NFKD French/Spanish) survive sanitization
         let sanitized = Self::sanitize_input(&case_normalized);
         if sanitized != case_normalized {
             changes_made.push("Removed non-alphabetic characters".to_string());
         }
 
-        // Step 4: Ensure proper word count and format
+        // Step 5: Ensure proper word count and format
         let final_formatted = Self::ensure_standard_format(&sanitized);
         let formatted_words: Vec<&str> = final_formatted.split_whitespace().collect();
         let formatted_word_count = formatted_words.len();
@@ -180,8 +595,19 @@ impl MnemonicFormatter {
             changes_made.push(format!("Adjusted word count from {} to {}", original_word_count, formatted_word_count));
         }
 
-        // Step 5: Validate the final format
-        let is_valid_format = Self::validate_format(&final_formatted);
+        // Step 6: Validate the final format (word count + alphabetic
+        // content) and, for the BIP39 length family, that every word
+        // actually belongs to the chosen language's wordlist. Monero's
+        // 13/25-word lengths use their own wordlist (not bundled here), so
+        // membership isn't checked for them - only the checksum word
+        // relation `validate_and_confirm_format` verifies separately.
+        let checksum_scheme = checksum_scheme_for_word_count(formatted_word_count);
+        let is_valid_format = Self::validate_format(&final_formatted)
+            && match checksum_scheme {
+                Some(ChecksumScheme::Bip39Sha256) => final_formatted.split_whitespace().all(|word| is_valid_bip39_word_in(word, language)),
+                Some(ChecksumScheme::MoneroCrc32) => true,
+                None => false,
+            };
 
         SeedPhraseFormatResult {
             formatted_seed_phrase: final_formatted,
@@ -189,6 +615,9 @@ impl MnemonicFormatter {
             formatted_word_count,
             changes_made,
             is_valid_format,
+            detected_language: language_name(language).to_string(),
+            nfkd_normalized,
+            checksum_scheme,
         }
     }
 
@@ -208,11 +637,15 @@ impl MnemonicFormatter {
         input.to_lowercase()
     }
 
-    /// Removes non-alphabetic characters except spaces
+    /// Removes stray characters except spaces, keeping every Unicode letter
+    /// (so non-Latin scripts like Japanese/Korean wordlists survive) and
+    /// combining marks (so NFKD-decomposed accented letters, e.g. Spanish/
+    /// French/Italian/Portuguese/Czech wordlists, aren't torn apart into a
+    /// bare base letter).
     pub fn sanitize_input(input: &str) -> String {
         input
             .chars()
-            .filter(|c| c.is_alphabetic() || c.is_whitespace())
+            .filter(|c| c.is_alphabetic() || is_combining_mark(*c) || c.is_whitespace())
             .collect::<String>()
             .split_whitespace()
             .collect::<Vec<&str>>()
@@ -243,13 +676,17 @@ impl MnemonicFormatter {
         let words: Vec<&str> = formatted.split_whitespace().collect();
         let word_count = words.len();
         
-        // Check if word count is valid for BIP39 (12, 15, 18, 21, 24)
-        let valid_word_counts = [12, 15, 18, 21, 24];
+        // Check if word count is valid for BIP39 (12, 15, 18, 21, 24) or a
+        // Monero legacy/standard seed (13, 25)
+        let valid_word_counts = [12, 13, 15, 18, 21, 24, 25];
         let has_valid_count = valid_word_counts.contains(&word_count);
         
-        // Check if all words are non-empty and contain only alphabetic characters
+        // Check if all words are non-empty and contain only letters (plus
+        // combining marks, since an NFKD-normalized accented word is a base
+        // letter followed by one or more combining marks, not a single
+        // precomposed letter)
         let all_words_valid = words.iter().all(|word| {
-            !word.is_empty() && word.chars().all(|c| c.is_alphabetic())
+            !word.is_empty() && word.chars().all(|c| c.is_alphabetic() || is_combining_mark(c))
         });
         
         has_valid_count && all_words_valid
@@ -265,14 +702,247 @@ impl MnemonicFormatter {
             return Err(MnemonicError::EmptyMnemonic);
         }
 
-        // Additional validation: check if word count is supported
-        let valid_counts = [12, 15, 18, 21, 24];
-        if !valid_counts.contains(&result.formatted_word_count) {
-            return Err(MnemonicError::InvalidWordCount(result.formatted_word_count));
+        // Additional validation: dispatch the checksum check by which
+        // scheme this word count implies; an unsupported count matches
+        // neither.
+        match checksum_scheme_for_word_count(result.formatted_word_count) {
+            Some(ChecksumScheme::Bip39Sha256) => Self::verify_bip39_checksum(&result.formatted_seed_phrase),
+            Some(ChecksumScheme::MoneroCrc32) => Self::verify_monero_checksum(&result.formatted_seed_phrase),
+            None => Err(MnemonicError::InvalidWordCount(result.formatted_word_count)),
+        }
+    }
+
+    /// Confirms every word belongs to the 2048-word English wordlist, then
+    /// verifies the embedded BIP39 checksum: each word maps to its 11-bit
+    /// index, all indices concatenate into an `N*11`-bit string, the
+    /// trailing `N*11/33` bits are the checksum and the leading `ENT =
+    /// N*11 - N*11/33` bits are the entropy. Re-hashing those `ENT` bits
+    /// (padded to bytes) with SHA-256 must reproduce the checksum bits in
+    /// the digest's first `ENT/32` bits.
+    fn verify_bip39_checksum(phrase: &str) -> Result<(), MnemonicError> {
+        let words: Vec<&str> = phrase.trim().split_whitespace().collect();
+        let wordlist = Language::English.word_list();
+
+        let total_bits = words.len() * 11;
+        let checksum_bits = total_bits / 33;
+        let entropy_bits = total_bits - checksum_bits;
+
+        let mut bits: Vec<bool> = Vec::with_capacity(total_bits);
+        for word in &words {
+            let index = wordlist.iter().position(|w| w == word).ok_or_else(|| MnemonicError::WordNotFound {
+                word: word.to_string(),
+                suggestions: nearest_wordlist_matches(word, wordlist, MAX_SUGGESTION_DISTANCE, MAX_SUGGESTIONS),
+            })?;
+            for bit in (0..11).rev() {
+                bits.push((index >> bit) & 1 == 1);
+            }
+        }
+
+        let mut entropy = vec![0u8; entropy_bits / 8];
+        for (i, bit) in bits[..entropy_bits].iter().enumerate() {
+            if *bit {
+                entropy[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&entropy);
+        let hash = hasher.finalize();
+
+        for i in 0..checksum_bits {
+            let phrase_bit = bits[entropy_bits + i];
+            let hash_bit = (hash[i / 8] >> (7 - (i % 8))) & 1 == 1;
+            if phrase_bit != hash_bit {
+                return Err(MnemonicError::InvalidChecksum);
+            }
         }
 
         Ok(())
     }
+
+    /// Verifies a Monero legacy (13-word) or standard (25-word) seed's
+    /// trailing checksum word: the last word must equal the payload word at
+    /// index `crc32(prefixes) % payload_len`, where `prefixes` is each
+    /// payload word trimmed to its first `MONERO_CHECKSUM_PREFIX_LEN`
+    /// characters, concatenated. This only checks that structural relation
+    /// - Monero uses its own wordlist (not bundled here), so, unlike
+    /// `verify_bip39_checksum`, individual word membership isn't checked.
+    fn verify_monero_checksum(phrase: &str) -> Result<(), MnemonicError> {
+        let words: Vec<&str> = phrase.trim().split_whitespace().collect();
+        let (payload_words, checksum_word) = words.split_at(words.len() - 1);
+        let checksum_word = checksum_word[0];
+
+        let mut trimmed_prefixes = String::new();
+        for word in payload_words {
+            let prefix_len = MONERO_CHECKSUM_PREFIX_LEN.min(word.chars().count());
+            trimmed_prefixes.extend(word.chars().take(prefix_len));
+        }
+
+        let checksum_index = (crc32_ieee(trimmed_prefixes.as_bytes()) as usize) % payload_words.len();
+        if payload_words[checksum_index] == checksum_word {
+            Ok(())
+        } else {
+            Err(MnemonicError::InvalidChecksum)
+        }
+    }
+
+    /// Decodes the `ENT` entropy bits encoded in a formatted phrase, after
+    /// verifying its BIP39 checksum (see `verify_bip39_checksum`). Only
+    /// defined for the BIP39 checksum scheme; Monero's 13/25-word lengths
+    /// don't round-trip through this, since Monero's own wordlist isn't
+    /// bundled here.
+    pub fn to_entropy(result: &SeedPhraseFormatResult) -> Result<Vec<u8>, MnemonicError> {
+        if result.checksum_scheme != Some(ChecksumScheme::Bip39Sha256) {
+            return Err(MnemonicError::InvalidWordCount(result.formatted_word_count));
+        }
+
+        Self::verify_bip39_checksum(&result.formatted_seed_phrase)?;
+
+        let words: Vec<&str> = result.formatted_seed_phrase.trim().split_whitespace().collect();
+        let wordlist = Language::English.word_list();
+        let total_bits = words.len() * 11;
+        let entropy_bits = total_bits - total_bits / 33;
+
+        let mut bits: Vec<bool> = Vec::with_capacity(entropy_bits);
+        for word in &words {
+            let index = wordlist
+                .iter()
+                .position(|w| w == word)
+                .expect("verify_bip39_checksum already confirmed every word is in the wordlist");
+            for bit in (0..11).rev() {
+                bits.push((index >> bit) & 1 == 1);
+            }
+        }
+
+        let mut entropy = vec![0u8; entropy_bits / 8];
+        for (i, bit) in bits[..entropy_bits].iter().enumerate() {
+            if *bit {
+                entropy[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+
+        Ok(entropy)
+    }
+
+    /// Builds a BIP39 English phrase from raw entropy: rejects byte lengths
+    /// that aren't both a multiple of 4 and within 16..=32 bytes, appends
+    /// the SHA-256-derived checksum bits, and emits the space-joined words.
+    /// The inverse of `to_entropy`.
+    pub fn from_entropy(entropy: &[u8]) -> Result<String, MnemonicError> {
+        if entropy.len() % 4 != 0 || !(16..=32).contains(&entropy.len()) {
+            return Err(MnemonicError::InvalidByteLength(entropy.len()));
+        }
+
+        let wordlist = Language::English.word_list();
+        let entropy_bits = entropy.len() * 8;
+        let checksum_bits = entropy_bits / 32;
+
+        let mut hasher = Sha256::new();
+        hasher.update(entropy);
+        let hash = hasher.finalize();
+
+        let mut bits: Vec<bool> = Vec::with_capacity(entropy_bits + checksum_bits);
+        for byte in entropy {
+            for bit in (0..8).rev() {
+                bits.push((byte >> bit) & 1 == 1);
+            }
+        }
+        for i in 0..checksum_bits {
+            bits.push((hash[i / 8] >> (7 - (i % 8))) & 1 == 1);
+        }
+
+        let words: Vec<&str> = bits
+            .chunks(11)
+            .map(|chunk| {
+                let index = chunk.iter().fold(0usize, |acc, &bit| (acc << 1) | bit as usize);
+                wordlist[index]
+            })
+            .collect();
+
+        Ok(words.join(" "))
+    }
+
+    /// Computes every valid last word for an 11- or 23-word BIP39 prefix,
+    /// against the English wordlist. See `complete_checksum_in` for the
+    /// language-aware version.
+    pub fn complete_checksum(prefix: &str) -> Result<Vec<String>, MnemonicError> {
+        Self::complete_checksum_in(prefix, Language::English)
+    }
+
+    /// Computes every valid last word for an 11- or 23-word prefix of
+    /// otherwise valid `language` wordlist words, i.e. every completion to
+    /// a full 12- or 24-word mnemonic that passes the BIP39 checksum.
+    ///
+    /// For 12 words the total is 132 bits (12 x 11): 128 entropy + 4
+    /// checksum. The 11 known words fix 121 of those bits, so the last
+    /// word's 11-bit index splits into 7 free entropy bits followed by the
+    /// 4 checksum bits. This iterates all 2^7 = 128 possibilities for the
+    /// free bits, assembles the full 128-bit entropy, SHA-256s it, and
+    /// takes the hash's leading 4 bits as the checksum - so every resulting
+    /// candidate is valid by construction. The 23-word/256-bit case works
+    /// the same way, with 3 free entropy bits and 8 checksum bits (2^3 = 8
+    /// completions).
+    pub fn complete_checksum_in(prefix: &str, language: Language) -> Result<Vec<String>, MnemonicError> {
+        let words: Vec<&str> = prefix.trim().split_whitespace().collect();
+        if words.is_empty() {
+            return Err(MnemonicError::EmptyMnemonic);
+        }
+        if words.len() != 11 && words.len() != 23 {
+            return Err(MnemonicError::InvalidWordCount(words.len()));
+        }
+
+        let wordlist = language.word_list();
+        let mut invalid_words = Vec::new();
+        let mut known_bits: Vec<bool> = Vec::with_capacity(words.len() * 11);
+        for word in &words {
+            match wordlist.iter().position(|w| w == word) {
+                Some(index) => {
+                    for bit in (0..11).rev() {
+                        known_bits.push((index >> bit) & 1 == 1);
+                    }
+                }
+                None => invalid_words.push(word.to_string()),
+            }
+        }
+        if !invalid_words.is_empty() {
+            return Err(MnemonicError::InvalidWords(invalid_words));
+        }
+
+        let total_bits = (words.len() + 1) * 11;
+        let checksum_bits = total_bits / 33;
+        let entropy_bits = total_bits - checksum_bits;
+        let free_bits = entropy_bits - known_bits.len();
+
+        let mut completions = Vec::with_capacity(1 << free_bits);
+        for candidate in 0u32..(1 << free_bits) {
+            let mut entropy_bit_buffer = known_bits.clone();
+            for bit in (0..free_bits).rev() {
+                entropy_bit_buffer.push((candidate >> bit) & 1 == 1);
+            }
+
+            let mut entropy = vec![0u8; entropy_bits / 8];
+            for (i, bit) in entropy_bit_buffer.iter().enumerate() {
+                if *bit {
+                    entropy[i / 8] |= 1 << (7 - (i % 8));
+                }
+            }
+
+            let mut hasher = Sha256::new();
+            hasher.update(&entropy);
+            let hash = hasher.finalize();
+
+            let mut checksum_value: usize = 0;
+            for i in 0..checksum_bits {
+                let hash_bit = (hash[i / 8] >> (7 - (i % 8))) & 1;
+                checksum_value = (checksum_value << 1) | hash_bit as usize;
+            }
+
+            let last_word_index = ((candidate as usize) << checksum_bits) | checksum_value;
+            completions.push(wordlist[last_word_index].to_string());
+        }
+
+        Ok(completions)
+    }
 }
 
 #[cfg(test)]
@@ -414,7 +1084,7 @@ mod tests {
     #[test]
     fn test_formatting_mixed_case() {
         let raw = "Abandon ABANDON abandon Abandon abandon abandon abandon abandon abandon abandon abandon about";
-        let formatted = MnemonicFormatter::clean_input(raw);
+        let formatted = SeedPhraseFormatter::clean_input(raw);
         // The clean_input function now converts to lowercase
         assert_eq!(formatted, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
     }
@@ -422,17 +1092,17 @@ mod tests {
     #[test]
     fn test_normalize_spacing() {
         let input = "abandon  abandon   abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
-        let normalized = MnemonicFormatter::normalize_spacing(input);
+        let normalized = SeedPhraseFormatter::normalize_spacing(input);
         assert_eq!(normalized, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
     }
 
     #[test]
     fn test_validate_format() {
-        assert!(MnemonicFormatter::validate_format("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
-        assert!(!MnemonicFormatter::validate_format("abandon abandon abandon")); // Too few words
-        assert!(!MnemonicFormatter::validate_format("")); // Empty
+        assert!(SeedPhraseFormatter::validate_format("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
+        assert!(!SeedPhraseFormatter::validate_format("abandon abandon abandon")); // Too few words
+        assert!(!SeedPhraseFormatter::validate_format("")); // Empty
         // Note: validate_format only checks word count and non-empty words, not spacing
-        assert!(MnemonicFormatter::validate_format("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
+        assert!(SeedPhraseFormatter::validate_format("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"));
     }
 
     #[test]
@@ -441,7 +1111,7 @@ mod tests {
         
         // Test complete flow: messy input -> format -> validate
         let messy_input = "  abandon   abandon  abandon abandon abandon abandon abandon abandon abandon abandon abandon about  ";
-        let formatted = MnemonicFormatter::clean_input(messy_input);
+        let formatted = SeedPhraseFormatter::clean_input(messy_input);
         let result = validator.validate_mnemonic(&formatted);
         
         assert!(result.is_valid);
@@ -454,7 +1124,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_clean_input() {
         let messy_input = "  abandon   abandon\nabandon\tabandon abandon abandon abandon abandon abandon abandon abandon about  ";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(messy_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(messy_input);
         
         assert_eq!(result.formatted_seed_phrase, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
         assert_eq!(result.original_word_count, 12);
@@ -466,7 +1136,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_case_normalization() {
         let mixed_case_input = "Abandon ABANDON abandon Abandon abandon abandon abandon abandon abandon abandon abandon about";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(mixed_case_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(mixed_case_input);
         
         assert_eq!(result.formatted_seed_phrase, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
         assert_eq!(result.original_word_count, 12);
@@ -478,7 +1148,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_sanitization() {
         let dirty_input = "abandon123 abandon! abandon@ abandon abandon abandon abandon abandon abandon abandon abandon about";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(dirty_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(dirty_input);
         
         assert_eq!(result.formatted_seed_phrase, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
         assert_eq!(result.original_word_count, 12);
@@ -490,7 +1160,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_word_count_change() {
         let short_input = "abandon abandon abandon";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(short_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(short_input);
         
         assert_eq!(result.formatted_seed_phrase, "abandon abandon abandon");
         assert_eq!(result.original_word_count, 3);
@@ -502,7 +1172,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_empty_input() {
         let empty_input = "   \n\t  ";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(empty_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(empty_input);
         
         assert_eq!(result.formatted_seed_phrase, "");
         assert_eq!(result.original_word_count, 0);
@@ -513,7 +1183,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_24_word_mnemonic() {
         let input_24 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(input_24);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(input_24);
         
         assert_eq!(result.original_word_count, 24);
         assert_eq!(result.formatted_word_count, 24);
@@ -523,54 +1193,54 @@ mod tests {
     #[test]
     fn test_normalize_case() {
         let mixed_case = "Abandon ABANDON abandon";
-        let normalized = MnemonicFormatter::normalize_case(mixed_case);
+        let normalized = SeedPhraseFormatter::normalize_case(mixed_case);
         assert_eq!(normalized, "abandon abandon abandon");
     }
 
     #[test]
     fn test_sanitize_input() {
         let dirty = "abandon123 abandon! abandon@ abandon";
-        let sanitized = MnemonicFormatter::sanitize_input(dirty);
+        let sanitized = SeedPhraseFormatter::sanitize_input(dirty);
         assert_eq!(sanitized, "abandon abandon abandon abandon");
     }
 
     #[test]
     fn test_sanitize_input_with_numbers_and_symbols() {
         let dirty = "word1 word2! word3@ word4# word5$ word6%";
-        let sanitized = MnemonicFormatter::sanitize_input(dirty);
+        let sanitized = SeedPhraseFormatter::sanitize_input(dirty);
         assert_eq!(sanitized, "word word word word word word");
     }
 
     #[test]
     fn test_ensure_standard_format() {
         let input = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
-        let formatted = MnemonicFormatter::ensure_standard_format(input);
+        let formatted = SeedPhraseFormatter::ensure_standard_format(input);
         assert_eq!(formatted, input);
     }
 
     #[test]
     fn test_validate_format_valid_counts() {
         // Test valid word counts
-        assert!(MnemonicFormatter::validate_format("word ".repeat(12).trim()));
-        assert!(MnemonicFormatter::validate_format("word ".repeat(15).trim()));
-        assert!(MnemonicFormatter::validate_format("word ".repeat(18).trim()));
-        assert!(MnemonicFormatter::validate_format("word ".repeat(21).trim()));
-        assert!(MnemonicFormatter::validate_format("word ".repeat(24).trim()));
+        assert!(SeedPhraseFormatter::validate_format("word ".repeat(12).trim()));
+        assert!(SeedPhraseFormatter::validate_format("word ".repeat(15).trim()));
+        assert!(SeedPhraseFormatter::validate_format("word ".repeat(18).trim()));
+        assert!(SeedPhraseFormatter::validate_format("word ".repeat(21).trim()));
+        assert!(SeedPhraseFormatter::validate_format("word ".repeat(24).trim()));
     }
 
     #[test]
     fn test_validate_format_invalid_counts() {
         // Test invalid word counts
-        assert!(!MnemonicFormatter::validate_format("word ".repeat(11).trim()));
-        assert!(!MnemonicFormatter::validate_format("word ".repeat(13).trim()));
-        assert!(!MnemonicFormatter::validate_format("word ".repeat(25).trim()));
+        assert!(!SeedPhraseFormatter::validate_format("word ".repeat(11).trim()));
+        assert!(!SeedPhraseFormatter::validate_format("word ".repeat(13).trim()));
+        assert!(!SeedPhraseFormatter::validate_format("word ".repeat(25).trim()));
     }
 
     #[test]
     fn test_validate_format_invalid_characters() {
         // Test words with numbers or symbols
-        assert!(!MnemonicFormatter::validate_format("word1 word2 word3 word4 word5 word6 word7 word8 word9 word10 word11 word12"));
-        assert!(!MnemonicFormatter::validate_format("word! word@ word# word$ word% word^ word& word* word( word) word- word="));
+        assert!(!SeedPhraseFormatter::validate_format("word1 word2 word3 word4 word5 word6 word7 word8 word9 word10 word11 word12"));
+        assert!(!SeedPhraseFormatter::validate_format("word! word@ word# word$ word% word^ word& word* word( word) word- word="));
     }
 
     #[test]
@@ -583,7 +1253,7 @@ mod tests {
             is_valid_format: true,
         };
         
-        assert!(MnemonicFormatter::validate_and_confirm_format(&valid_result).is_ok());
+        assert!(SeedPhraseFormatter::validate_and_confirm_format(&valid_result).is_ok());
     }
 
     #[test]
@@ -596,7 +1266,7 @@ mod tests {
             is_valid_format: false,
         };
         
-        let result = MnemonicFormatter::validate_and_confirm_format(&invalid_result);
+        let result = SeedPhraseFormatter::validate_and_confirm_format(&invalid_result);
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), MnemonicError::InvalidWordCount(3)));
     }
@@ -611,7 +1281,7 @@ mod tests {
             is_valid_format: false,
         };
         
-        let result = MnemonicFormatter::validate_and_confirm_format(&empty_result);
+        let result = SeedPhraseFormatter::validate_and_confirm_format(&empty_result);
         assert!(result.is_err());
         // The function checks is_valid_format first, so it returns InvalidWordCount(0) instead of EmptyMnemonic
         assert!(matches!(result.unwrap_err(), MnemonicError::InvalidWordCount(0)));
@@ -620,7 +1290,7 @@ mod tests {
     #[test]
     fn test_comprehensive_formatting_complex_scenario() {
         let complex_input = "  Abandon123   ABANDON!  \n abandon@  abandon abandon abandon abandon abandon abandon abandon abandon about  ";
-        let result = MnemonicFormatter::format_mnemonic_comprehensive(complex_input);
+        let result = SeedPhraseFormatter::format_seed_phrase_comprehensive(complex_input);
         
         assert_eq!(result.formatted_seed_phrase, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about");
         assert_eq!(result.original_word_count, 12);
@@ -0,0 +1,292 @@
+//! X25519-based public-key encryption so a mnemonic can be unlocked by any
+//! one of several trusted recipients (e.g. the owner plus a trustee),
+//! instead of a single shared passphrase.
+//!
+//! A random ephemeral X25519 keypair is generated per encryption. For each
+//! recipient, an ECDH shared secret (ephemeral secret x recipient public key)
+//! is run through HKDF-SHA256 to derive a key that wraps one randomly
+//! generated content key; the mnemonic itself is AES-256-GCM-encrypted once
+//! under that content key. Decryption tries each wrapped-key entry against
+//! the local private key until one unwraps.
+//!
+//! Two ways to get an `X25519KeyPair`, mirroring the two trust models this
+//! is meant to support:
+//! - Explicit-trust mode (`X25519KeyPair::generate`): a random keypair kept
+//!   locally; its public key is shared with peers who should be able to
+//!   decrypt.
+//! - Shared-secret mode (`X25519KeyPair::from_passphrase`): every holder of
+//!   the same passphrase derives the identical keypair, so the passphrase
+//!   itself is the trust anchor and the only "recipient" a holder needs to
+//!   encrypt to is their own derived public key.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use base64::{engine::general_purpose, Engine as _};
+use hkdf::Hkdf;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+use crate::crypto::SafePassword;
+
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const WRAP_INFO: &[u8] = b"btc-mnemonic-encryptor:multi-recipient-wrap";
+const SHARED_SECRET_KEYPAIR_INFO: &[u8] = b"btc-mnemonic-encryptor:shared-secret-keypair";
+
+#[derive(Error, Debug)]
+pub enum MultiRecipientError {
+    #[error("at least one recipient public key is required")]
+    NoRecipients,
+    #[error("invalid public key bytes")]
+    InvalidKey,
+    #[error("invalid base64 data: {0}")]
+    InvalidData(String),
+    #[error("key wrapping failed: {0}")]
+    WrapFailed(String),
+    #[error("content encryption failed: {0}")]
+    EncryptionFailed(String),
+    #[error("content decryption failed: {0}")]
+    DecryptionFailed(String),
+    #[error("none of the wrapped keys could be unwrapped with this private key")]
+    NoMatchingRecipient,
+}
+
+impl serde::Serialize for MultiRecipientError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// An X25519 identity: a private scalar plus the public key derived from it.
+pub struct X25519KeyPair {
+    secret: StaticSecret,
+    public: PublicKey,
+}
+
+impl X25519KeyPair {
+    /// Explicit-trust mode: a fresh random keypair. Keep `secret` local and
+    /// share `public_key_bytes()` with peers who should be able to encrypt
+    /// to this identity.
+    pub fn generate() -> Self {
+        let secret = StaticSecret::random_from_rng(OsRng);
+        let public = PublicKey::from(&secret);
+        Self { secret, public }
+    }
+
+    /// Shared-secret mode: deterministically derives the same keypair for
+    /// every holder of `passphrase`, via HKDF-SHA256 of the passphrase
+    /// bytes. The passphrase is the trust anchor here, not a locally kept
+    /// private key, so every holder only ever needs to encrypt to their own
+    /// derived public key.
+    pub fn from_passphrase(passphrase: &SafePassword) -> Self {
+        let hk = Hkdf::<Sha256>::new(None, passphrase.as_str().as_bytes());
+        let mut scalar_bytes = [0u8; KEY_SIZE];
+        hk.expand(SHARED_SECRET_KEYPAIR_INFO, &mut scalar_bytes)
+            .expect("KEY_SIZE is a valid HKDF-SHA256 output length");
+        let secret = StaticSecret::from(scalar_bytes);
+        scalar_bytes.zeroize();
+        let public = PublicKey::from(&secret);
+        Self { secret, public }
+    }
+
+    /// Reconstructs a previously generated explicit-trust keypair from its
+    /// saved private key bytes (see `private_key_bytes`).
+    pub fn from_private_key_bytes(bytes: [u8; KEY_SIZE]) -> Self {
+        let secret = StaticSecret::from(bytes);
+        let public = PublicKey::from(&secret);
+        Self { secret, public }
+    }
+
+    pub fn public_key_bytes(&self) -> [u8; KEY_SIZE] {
+        self.public.to_bytes()
+    }
+
+    /// Raw private scalar bytes, for an explicit-trust keypair the caller
+    /// needs to persist across sessions (a shared-secret keypair never needs
+    /// this — it's re-derived from the passphrase each time).
+    pub fn private_key_bytes(&self) -> [u8; KEY_SIZE] {
+        self.secret.to_bytes()
+    }
+
+    /// Raw ECDH shared secret with `peer_public`. `pub(crate)` so sibling
+    /// modules building their own HKDF-wrapped envelope on top of this same
+    /// X25519 identity (see `shard::split_mnemonic`) don't need to duplicate
+    /// key material handling.
+    pub(crate) fn diffie_hellman(&self, peer_public: &PublicKey) -> x25519_dalek::SharedSecret {
+        self.secret.diffie_hellman(peer_public)
+    }
+}
+
+/// One recipient's wrapped copy of the content key.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WrappedContentKey {
+    /// Base64 public key this entry was wrapped for, so a holder can find
+    /// their own entry without unwrap attempts against every other one.
+    pub recipient_public_key: String,
+    pub wrap_nonce: String,
+    pub wrapped_key: String,
+}
+
+/// A mnemonic encrypted to a set of recipients: one AEAD ciphertext under a
+/// random content key, plus one wrapped copy of that content key per
+/// recipient.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultiRecipientContainer {
+    /// Base64 ephemeral public key generated for this encryption, used by
+    /// every recipient's ECDH step.
+    pub ephemeral_public_key: String,
+    pub wrapped_keys: Vec<WrappedContentKey>,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    general_purpose::STANDARD.encode(bytes)
+}
+
+pub(crate) fn decode(encoded: &str) -> Result<Vec<u8>, MultiRecipientError> {
+    general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| MultiRecipientError::InvalidData(e.to_string()))
+}
+
+pub(crate) fn decode_public_key(encoded: &str) -> Result<[u8; KEY_SIZE], MultiRecipientError> {
+    decode(encoded)?
+        .try_into()
+        .map_err(|_| MultiRecipientError::InvalidKey)
+}
+
+/// Derives the AES-256-GCM key that wraps the content key for one recipient:
+/// HKDF-SHA256 of the ECDH shared secret, bound to both the ephemeral and
+/// recipient public keys so a wrapped-key entry can't be replayed against a
+/// different ephemeral/recipient pairing. `pub(crate)` so `shard` can derive
+/// its own per-share wrap keys the same way, with its own domain-separation
+/// info string.
+pub(crate) fn derive_wrap_key(shared_secret: &[u8; KEY_SIZE], ephemeral_public: &[u8; KEY_SIZE], recipient_public: &[u8; KEY_SIZE]) -> [u8; KEY_SIZE] {
+    let hk = Hkdf::<Sha256>::new(None, shared_secret);
+    let mut info = Vec::with_capacity(WRAP_INFO.len() + KEY_SIZE * 2);
+    info.extend_from_slice(WRAP_INFO);
+    info.extend_from_slice(ephemeral_public);
+    info.extend_from_slice(recipient_public);
+
+    let mut wrap_key = [0u8; KEY_SIZE];
+    hk.expand(&info, &mut wrap_key)
+        .expect("KEY_SIZE is a valid HKDF-SHA256 output length");
+    wrap_key
+}
+
+/// Encrypts `plaintext` to every key in `recipient_public_keys`: any one of
+/// their matching private keys can decrypt it via `decrypt_with_private_key`.
+pub fn encrypt_for_recipients(
+    plaintext: &[u8],
+    recipient_public_keys: &[[u8; KEY_SIZE]],
+) -> Result<MultiRecipientContainer, MultiRecipientError> {
+    if recipient_public_keys.is_empty() {
+        return Err(MultiRecipientError::NoRecipients);
+    }
+
+    let ephemeral = X25519KeyPair::generate();
+    let ephemeral_public_bytes = ephemeral.public_key_bytes();
+
+    let mut content_key = [0u8; KEY_SIZE];
+    OsRng.fill_bytes(&mut content_key);
+
+    let mut nonce_bytes = [0u8; NONCE_SIZE];
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let content_cipher = Aes256Gcm::new_from_slice(&content_key)
+        .map_err(|e| MultiRecipientError::EncryptionFailed(e.to_string()))?;
+    let ciphertext = content_cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|e| MultiRecipientError::EncryptionFailed(e.to_string()))?;
+
+    let mut wrapped_keys = Vec::with_capacity(recipient_public_keys.len());
+    for recipient_bytes in recipient_public_keys {
+        let recipient_public = PublicKey::from(*recipient_bytes);
+        let shared_secret = ephemeral.diffie_hellman(&recipient_public);
+        let wrap_key = derive_wrap_key(shared_secret.as_bytes(), &ephemeral_public_bytes, recipient_bytes);
+
+        let mut wrap_nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut wrap_nonce);
+
+        let wrap_cipher = Aes256Gcm::new_from_slice(&wrap_key)
+            .map_err(|e| MultiRecipientError::WrapFailed(e.to_string()))?;
+        let wrapped_key = wrap_cipher
+            .encrypt(Nonce::from_slice(&wrap_nonce), content_key.as_ref())
+            .map_err(|e| MultiRecipientError::WrapFailed(e.to_string()))?;
+
+        wrapped_keys.push(WrappedContentKey {
+            recipient_public_key: encode(recipient_bytes),
+            wrap_nonce: encode(&wrap_nonce),
+            wrapped_key: encode(&wrapped_key),
+        });
+    }
+
+    content_key.zeroize();
+
+    Ok(MultiRecipientContainer {
+        ephemeral_public_key: encode(&ephemeral_public_bytes),
+        wrapped_keys,
+        nonce: encode(&nonce_bytes),
+        ciphertext: encode(&ciphertext),
+    })
+}
+
+/// Tries `keypair`'s private key against every wrapped-key entry in
+/// `container` until one unwraps, then decrypts the mnemonic with the
+/// recovered content key. Fails with `NoMatchingRecipient` if `keypair`
+/// wasn't one of the recipients `container` was encrypted to.
+pub fn decrypt_with_private_key(
+    container: &MultiRecipientContainer,
+    keypair: &X25519KeyPair,
+) -> Result<Vec<u8>, MultiRecipientError> {
+    let ephemeral_public_bytes = decode_public_key(&container.ephemeral_public_key)?;
+    let ephemeral_public = PublicKey::from(ephemeral_public_bytes);
+    let my_public_bytes = keypair.public_key_bytes();
+    let shared_secret = keypair.diffie_hellman(&ephemeral_public);
+
+    for entry in &container.wrapped_keys {
+        let wrap_key = derive_wrap_key(shared_secret.as_bytes(), &ephemeral_public_bytes, &my_public_bytes);
+        let wrap_nonce = decode(&entry.wrap_nonce)?;
+        let wrapped_key = decode(&entry.wrapped_key)?;
+
+        let wrap_cipher = match Aes256Gcm::new_from_slice(&wrap_key) {
+            Ok(cipher) => cipher,
+            Err(_) => continue,
+        };
+
+        let Ok(mut content_key_bytes) = wrap_cipher.decrypt(Nonce::from_slice(&wrap_nonce), wrapped_key.as_ref()) else {
+            continue;
+        };
+
+        let content_key: [u8; KEY_SIZE] = match content_key_bytes.as_slice().try_into() {
+            Ok(key) => key,
+            Err(_) => {
+                content_key_bytes.zeroize();
+                continue;
+            }
+        };
+        content_key_bytes.zeroize();
+
+        let nonce = decode(&container.nonce)?;
+        let ciphertext = decode(&container.ciphertext)?;
+        let content_cipher = Aes256Gcm::new_from_slice(&content_key)
+            .map_err(|e| MultiRecipientError::DecryptionFailed(e.to_string()))?;
+        let plaintext = content_cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|e| MultiRecipientError::DecryptionFailed(e.to_string()))?;
+
+        return Ok(plaintext);
+    }
+
+    Err(MultiRecipientError::NoMatchingRecipient)
+}
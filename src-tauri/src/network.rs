@@ -1,22 +1,79 @@
-use std::process::Command;
+use std::io::{Read, Write};
 use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
 use tokio::time::timeout;
 use std::net::{TcpStream, ToSocketAddrs};
 
 const TEST_HOSTS: &[&str] = &["8.8.8.8", "1.1.1.1", "114.114.114.114", "223.5.5.5", "208.67.222.222"];
-const HTTP_TEST_HOSTS: &[&str] = &["www.google.com", "www.cloudflare.com", "www.baidu.com", "www.taobao.com", "www.apple.com"];
 const TIMEOUT_MS: u64 = 1500; // Reduced timeout for faster detection
 const DNS_PORT: u16 = 53;
 const HTTP_PORT: u16 = 80;
 
+/// One well-known "generate 204" style endpoint used to tell a genuine
+/// internet connection apart from a captive portal: a real connection
+/// returns exactly `expected_status`/`expected_body`, while a captive
+/// portal intercepts the request and answers with its own redirect or
+/// login page instead.
+struct CaptivePortalProbe {
+    host: &'static str,
+    path: &'static str,
+    expected_status: u16,
+    /// `None` means a genuine response must have an empty body.
+    expected_body: Option<&'static str>,
+}
+
+const CAPTIVE_PORTAL_PROBES: &[CaptivePortalProbe] = &[
+    CaptivePortalProbe {
+        host: "connectivitycheck.gstatic.com",
+        path: "/generate_204",
+        expected_status: 204,
+        expected_body: None,
+    },
+    CaptivePortalProbe {
+        host: "cp.cloudflare.com",
+        path: "/generate_204",
+        expected_status: 204,
+        expected_body: None,
+    },
+    CaptivePortalProbe {
+        host: "detectportal.firefox.com",
+        path: "/success.txt",
+        expected_status: 200,
+        expected_body: Some("success"),
+    },
+];
+
+/// Outcome of probing a generate-204-style endpoint over plain HTTP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpProbeResult {
+    /// No response at all (connection, write, or read failed) — offline.
+    NoResponse,
+    /// Got exactly the expected response — a genuine, unintercepted
+    /// connection to the internet.
+    Connected,
+    /// Got a response, but not the one expected (redirect, login page,
+    /// wrong status) — something on the network is intercepting HTTP
+    /// requests, the hallmark of a captive portal.
+    CaptivePortal,
+}
+
 pub async fn is_network_connected() -> bool {
     // Primary check: Test actual internet connectivity (most reliable)
     let dns_working = test_dns_connectivity().await;
-    let http_working = test_http_connectivity().await;
-    
+    let http_probe = probe_captive_portal().await;
+
+    // A captive portal is actively intercepting our traffic. That's a
+    // security risk in its own right (another party is in the path of
+    // every request), regardless of what the DNS probe reported.
+    if http_probe == HttpProbeResult::CaptivePortal {
+        return true;
+    }
+    let http_working = http_probe == HttpProbeResult::Connected;
+
     // Secondary check: Look for active network interfaces with real IPs
     let has_real_interfaces = has_real_network_interfaces();
-    
+
     // Smart security assessment (following Java implementation logic)
     if dns_working && http_working {
         // Both DNS and HTTP work - definitely unsafe
@@ -53,15 +110,78 @@ async fn test_dns_connectivity() -> bool {
     false
 }
 
-async fn test_http_connectivity() -> bool {
-    for host in HTTP_TEST_HOSTS {
-        if let Ok(result) = timeout(Duration::from_millis(TIMEOUT_MS), test_tcp_connection(host, HTTP_PORT)).await {
-            if result {
-                return true;
-            }
+/// Tries each captive-portal probe endpoint in turn, returning the first
+/// non-`NoResponse` result (an endpoint that's unreachable tells us
+/// nothing, so we move on to the next one rather than concluding we're
+/// offline).
+async fn probe_captive_portal() -> HttpProbeResult {
+    for probe in CAPTIVE_PORTAL_PROBES {
+        let result = timeout(Duration::from_millis(TIMEOUT_MS * 2), run_captive_portal_probe(probe))
+            .await
+            .unwrap_or(HttpProbeResult::NoResponse);
+        if result != HttpProbeResult::NoResponse {
+            return result;
         }
     }
-    false
+    HttpProbeResult::NoResponse
+}
+
+async fn run_captive_portal_probe(probe: &CaptivePortalProbe) -> HttpProbeResult {
+    let address = format!("{}:{}", probe.host, HTTP_PORT);
+    let Some(addr) = address.to_socket_addrs().ok().and_then(|mut addrs| addrs.next()) else {
+        return HttpProbeResult::NoResponse;
+    };
+
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, Duration::from_millis(TIMEOUT_MS)) else {
+        return HttpProbeResult::NoResponse;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_millis(TIMEOUT_MS)));
+    let _ = stream.set_write_timeout(Some(Duration::from_millis(TIMEOUT_MS)));
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: btc-mnemonic-encryptor\r\nConnection: close\r\n\r\n",
+        probe.path, probe.host
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return HttpProbeResult::NoResponse;
+    }
+
+    let mut response = Vec::new();
+    let _ = stream.read_to_end(&mut response);
+    if response.is_empty() {
+        return HttpProbeResult::NoResponse;
+    }
+
+    parse_captive_portal_response(&response, probe)
+}
+
+/// Parses a raw HTTP response and checks it against what `probe` expects:
+/// a real generate-204 endpoint returns exactly that status and body, while
+/// a captive portal's interception (a redirect, an HTML login page, a
+/// different status) fails the comparison.
+fn parse_captive_portal_response(response: &[u8], probe: &CaptivePortalProbe) -> HttpProbeResult {
+    let text = String::from_utf8_lossy(response);
+    let mut sections = text.splitn(2, "\r\n\r\n");
+    let head = sections.next().unwrap_or("");
+    let body = sections.next().unwrap_or("").trim();
+
+    let status_code: u16 = head
+        .lines()
+        .next()
+        .and_then(|status_line| status_line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .unwrap_or(0);
+
+    let body_matches = match probe.expected_body {
+        Some(expected) => body == expected,
+        None => body.is_empty(),
+    };
+
+    if status_code == probe.expected_status && body_matches {
+        HttpProbeResult::Connected
+    } else {
+        HttpProbeResult::CaptivePortal
+    }
 }
 
 async fn test_tcp_connection(host: &str, port: u16) -> bool {
@@ -83,67 +203,21 @@ async fn test_tcp_connection(host: &str, port: u16) -> bool {
     }
 }
 
+/// Enumerates local interfaces natively (`getifaddrs` on Unix, the IP Helper
+/// API / `GetAdaptersAddresses` on Windows, both via the `if-addrs` crate)
+/// instead of shelling out to `ifconfig`/`route`, which are absent on
+/// iproute2-only Linux systems and don't exist on Windows at all. Reuses the
+/// same `is_virtual_interface`/`is_real_ip_address` filtering, just fed
+/// structured interface/address data instead of scraped command output.
 fn has_real_network_interfaces() -> bool {
-    // Check network interfaces using ifconfig (more reliable than route table)
-    match Command::new("ifconfig").output() {
-        Ok(output) => {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            parse_ifconfig_output(&output_str)
-        }
-        Err(_) => {
-            // Fallback to checking route table
-            check_default_route()
-        }
-    }
-}
-
-fn parse_ifconfig_output(output: &str) -> bool {
-    let mut current_interface = String::new();
-    let mut interface_is_up = false;
-    let mut has_real_interface = false;
-    
-    for line in output.lines() {
-        let line_trimmed = line.trim();
-        
-        // New interface starts (doesn't start with whitespace)
-        if !line.starts_with(' ') && !line.starts_with('\t') && line.contains(':') {
-            if let Some(interface_name) = line.split(':').next() {
-                current_interface = interface_name.to_lowercase();
-                // Check if interface is UP and RUNNING
-                interface_is_up = line.to_uppercase().contains("UP") && 
-                                 line.to_uppercase().contains("RUNNING");
-            }
-        }
-        
-        // Check for inet addresses only if interface is UP
-        if interface_is_up && line_trimmed.starts_with("inet ") {
-            if let Some(ip_part) = line_trimmed.split_whitespace().nth(1) {
-                let ip = ip_part.split('/').next().unwrap_or(ip_part);
-                
-                // Skip loopback
-                if ip == "127.0.0.1" {
-                    continue;
-                }
-                
-                // Skip APIPA addresses
-                if ip.starts_with("169.254.") {
-                    continue;
-                }
-                
-                // Skip common virtual interfaces
-                if is_virtual_interface(&current_interface) {
-                    continue;
-                }
-                
-                // Check if it's a private/public IP (real network)
-                if is_real_ip_address(ip) {
-                    has_real_interface = true;
-                }
-            }
-        }
+    match if_addrs::get_if_addrs() {
+        Ok(interfaces) => interfaces.iter().any(|iface| {
+            !iface.is_loopback()
+                && !is_virtual_interface(&iface.name.to_lowercase())
+                && is_real_ip_address(&iface.ip().to_string())
+        }),
+        Err(_) => false,
     }
-    
-    has_real_interface
 }
 
 fn is_real_ip_address(ip: &str) -> bool {
@@ -193,71 +267,92 @@ fn is_virtual_interface(interface_name: &str) -> bool {
     name == "lo0"                    // macOS loopback
 }
 
-fn check_default_route() -> bool {
-    // Check if there's a default route (indicates potential network connectivity)
-    match Command::new("route")
-        .args(&["-n", "get", "default"])
-        .output()
-    {
-        Ok(output) => {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            
-            // Look for gateway and interface, but be more specific
-            let has_gateway = output_str.contains("gateway:");
-            let has_interface = output_str.contains("interface:");
-            
-            // Only consider it connected if both gateway and interface are present
-            // and it's not just a loopback or virtual interface
-            if has_gateway && has_interface {
-                // Check if the interface is real (not lo0, utun, etc.)
-                for line in output_str.lines() {
-                    if line.trim().starts_with("interface:") {
-                        if let Some(interface) = line.split(':').nth(1) {
-                            let interface = interface.trim();
-                            return !is_virtual_interface(interface);
-                        }
-                    }
-                }
-            }
-            
-            false
-        }
-        Err(_) => false,
+/// Default interval between connectivity polls.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default number of consecutive matching readings required before a
+/// transition is published (see `monitor_network_changes`).
+pub const DEFAULT_DEBOUNCE_COUNT: u32 = 2;
+
+/// A running background connectivity monitor. Dropping the handle (or
+/// calling `stop`) ends the poll loop; `subscribe` hands out a
+/// `watch::Receiver` a caller can `.changed().await` on to wake the instant
+/// connectivity flips between safe (`false`) and unsafe (`true`) — e.g. to
+/// abort an in-progress decryption the moment a live interface with a real
+/// IP reappears mid-operation.
+pub struct NetworkMonitorHandle {
+    watch_rx: watch::Receiver<bool>,
+    task: JoinHandle<()>,
+}
+
+impl NetworkMonitorHandle {
+    /// The most recently published (debounced) connectivity state.
+    pub fn is_connected(&self) -> bool {
+        *self.watch_rx.borrow()
+    }
+
+    /// A receiver that wakes on every debounced safe/unsafe transition.
+    /// Each subscriber gets its own cursor, so multiple callers (e.g. an
+    /// in-flight encryption and a UI status indicator) can watch
+    /// independently.
+    pub fn subscribe(&self) -> watch::Receiver<bool> {
+        self.watch_rx.clone()
+    }
+
+    /// Stops the background poll loop. Safe to call more than once, and
+    /// happens automatically when the handle is dropped.
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for NetworkMonitorHandle {
+    fn drop(&mut self) {
+        self.task.abort();
     }
 }
 
-// Additional check using netstat to see active connections
-#[allow(dead_code)]
-fn check_active_connections() -> bool {
-    match Command::new("netstat")
-        .args(&["-rn"])
-        .output()
-    {
-        Ok(output) => {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            
-            // Look for default route (0.0.0.0 or default)
-            for line in output_str.lines() {
-                if line.contains("0.0.0.0") || line.contains("default") {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 4 {
-                        let interface = parts[parts.len() - 1];
-                        if !is_virtual_interface(interface) {
-                            return true;
-                        }
-                    }
+/// Starts a background task that polls `is_network_connected` every
+/// `poll_interval` and publishes a transition through the returned handle's
+/// `watch` channel only once the same reading has been observed
+/// `debounce_count` times in a row — so a single flaky poll (a transient DNS
+/// timeout, a momentary Wi-Fi hiccup) doesn't flip a caller's state back and
+/// forth. Stops publishing (and exits) once every receiver has been dropped.
+pub async fn monitor_network_changes(
+    poll_interval: Duration,
+    debounce_count: u32,
+) -> NetworkMonitorHandle {
+    let debounce_count = debounce_count.max(1);
+    let initial = is_network_connected().await;
+    let (tx, rx) = watch::channel(initial);
+
+    let task = tokio::spawn(async move {
+        let mut published = initial;
+        let mut candidate = initial;
+        let mut streak: u32 = 1;
+
+        loop {
+            tokio::time::sleep(poll_interval).await;
+            let reading = is_network_connected().await;
+
+            if reading == candidate {
+                streak += 1;
+            } else {
+                candidate = reading;
+                streak = 1;
+            }
+
+            if streak >= debounce_count && candidate != published {
+                published = candidate;
+                if tx.send(candidate).is_err() {
+                    // No receivers left; nothing more to watch for.
+                    return;
                 }
             }
-            false
         }
-        Err(_) => false,
-    }
-}
+    });
 
-pub async fn monitor_network_changes() -> Result<(), Box<dyn std::error::Error>> {
-    // This could be extended to monitor network changes in real-time
-    // For now, it's a placeholder for future functionality
-    Ok(())
+    NetworkMonitorHandle { watch_rx: rx, task }
 }
 
 pub fn get_network_warning_message() -> String {
@@ -294,4 +389,13 @@ mod tests {
         assert!(message.contains("Network Connection Detected"));
         assert!(message.contains("disconnect from the internet"));
     }
+
+    #[tokio::test]
+    async fn test_monitor_reports_initial_state_and_stops() {
+        let handle = monitor_network_changes(Duration::from_millis(50), 1).await;
+        let expected = is_network_connected().await;
+        assert_eq!(handle.is_connected(), expected);
+
+        handle.stop();
+    }
 }
\ No newline at end of file
@@ -0,0 +1,86 @@
+//! QR-code transport for a mnemonic or an encrypted share (see `shard`),
+//! for moving words or public keys between machines over an air gap
+//! instead of a network link this crate otherwise refuses to use for
+//! secret material. Entirely optional - gated behind the `qrcode` feature
+//! since most builds never need a scanner/renderer pulled in.
+
+use image::{DynamicImage, Luma};
+use qrcode::{EcLevel, QrCode};
+use thiserror::Error;
+
+use crate::mnemonic::Mnemonic;
+use crate::shard::EncryptedShare;
+
+#[derive(Error, Debug)]
+pub enum QrError {
+    #[error("failed to encode QR code: {0}")]
+    EncodeFailed(String),
+    #[error("no QR code could be decoded from the scanned image")]
+    ScanFailed,
+    #[error("decoded QR payload wasn't valid: {0}")]
+    InvalidPayload(String),
+}
+
+impl serde::Serialize for QrError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// An error-corrected QR matrix rendered as a grayscale image, ready to
+/// display on screen or send to a printer for an air-gapped handoff.
+pub struct QrImage {
+    pub image: DynamicImage,
+    pub width: u32,
+}
+
+fn encode_to_qr(data: &[u8]) -> Result<QrImage, QrError> {
+    // Error-correction level M recovers from ~15% damage or obstruction -
+    // enough for a printed card that's been folded or partially obscured.
+    let code = QrCode::with_error_correction_level(data, EcLevel::M).map_err(|e| QrError::EncodeFailed(e.to_string()))?;
+    let image = code.render::<Luma<u8>>().build();
+    let width = image.width();
+    Ok(QrImage { image: DynamicImage::ImageLuma8(image), width })
+}
+
+fn decode_from_qr(image: &DynamicImage) -> Result<String, QrError> {
+    let luma = image.to_luma8();
+    let mut prepared = rqrr::PreparedImage::prepare(luma);
+    let grids = prepared.detect_grids();
+    let grid = grids.first().ok_or(QrError::ScanFailed)?;
+    let (_, payload) = grid.decode().map_err(|_| QrError::ScanFailed)?;
+    Ok(payload)
+}
+
+/// Renders `mnemonic`'s phrase into a QR code.
+pub fn mnemonic_to_qr(mnemonic: &Mnemonic) -> Result<QrImage, QrError> {
+    encode_to_qr(mnemonic.phrase.as_bytes())
+}
+
+/// Decodes a scanned QR image back into a `Mnemonic`. Returns
+/// `QrError::ScanFailed` rather than panicking on a bad scan, so a caller
+/// can fall back to `interactive::prompt_mnemonic` for manual text entry.
+pub fn mnemonic_from_qr(image: &DynamicImage) -> Result<Mnemonic, QrError> {
+    let phrase = decode_from_qr(image)?;
+    let word_count = phrase.split_whitespace().count();
+    if word_count == 0 {
+        return Err(QrError::InvalidPayload("decoded phrase is empty".to_string()));
+    }
+    Ok(Mnemonic { phrase, word_count, language: "english".to_string() })
+}
+
+/// Renders one `EncryptedShare` (see `shard::split_mnemonic`) into a QR
+/// code, as its JSON serialization.
+pub fn share_to_qr(share: &EncryptedShare) -> Result<QrImage, QrError> {
+    let json = serde_json::to_vec(share).map_err(|e| QrError::EncodeFailed(e.to_string()))?;
+    encode_to_qr(&json)
+}
+
+/// Decodes a scanned QR image back into an `EncryptedShare`.
+pub fn share_from_qr(image: &DynamicImage) -> Result<EncryptedShare, QrError> {
+    let payload = decode_from_qr(image)?;
+    serde_json::from_str(&payload).map_err(|e| QrError::InvalidPayload(e.to_string()))
+}
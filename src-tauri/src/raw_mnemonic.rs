@@ -0,0 +1,144 @@
+//! Non-checksummed wordlist encoding for arbitrary payload bytes (AES-GCM
+//! nonces, X25519 public keys, ciphertext blocks), so they can be
+//! transcribed by hand the way a BIP39 mnemonic is - without being one:
+//! there's no checksum word appended, and a `RawMnemonic`'s words carry no
+//! guarantee of forming valid BIP39 entropy. Use this to transport bytes
+//! you already trust, not as a user-memorized secret.
+
+use bip39::Language;
+
+/// A sequence of wordlist-indexed words encoding arbitrary bytes, built by
+/// `from_raw_bytes_unchecked` and inverted by `to_raw_bytes`. Unlike a real
+/// BIP39 mnemonic this carries no checksum word, so a mistyped word can't
+/// be detected as invalid - it just decodes to the wrong bytes.
+pub struct RawMnemonic {
+    words: Vec<String>,
+    byte_len: usize,
+}
+
+impl RawMnemonic {
+    /// Chops `bytes` into 11-bit groups (zero-padding the final, partial
+    /// group) and maps each group to an English wordlist index, with no
+    /// BIP39 checksum appended. The `_unchecked` name mirrors Rust's own
+    /// `str::from_utf8_unchecked` convention: nothing here validates that
+    /// the resulting words are anything more than an encoding of `bytes`.
+    /// See `from_raw_bytes_unchecked_in` for other wordlists.
+    pub fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        Self::from_raw_bytes_unchecked_in(bytes, Language::English)
+    }
+
+    /// `from_raw_bytes_unchecked`, against `language`'s wordlist.
+    pub fn from_raw_bytes_unchecked_in(bytes: &[u8], language: Language) -> Self {
+        let wordlist = language.word_list();
+
+        let mut bits: Vec<bool> = Vec::with_capacity(bytes.len() * 8);
+        for byte in bytes {
+            for bit in (0..8).rev() {
+                bits.push((byte >> bit) & 1 == 1);
+            }
+        }
+
+        let padding = (11 - bits.len() % 11) % 11;
+        bits.extend(std::iter::repeat(false).take(padding));
+
+        let words = bits
+            .chunks(11)
+            .map(|chunk| {
+                let index = chunk.iter().fold(0usize, |acc, &bit| (acc << 1) | bit as usize);
+                wordlist[index].to_string()
+            })
+            .collect();
+
+        Self { words, byte_len: bytes.len() }
+    }
+
+    /// Reconstructs the original bytes, against the English wordlist,
+    /// discarding the zero-padding bits `from_raw_bytes_unchecked` added to
+    /// the final group. See `to_raw_bytes_in` for other wordlists.
+    pub fn to_raw_bytes(&self) -> Vec<u8> {
+        self.to_raw_bytes_in(Language::English)
+    }
+
+    /// `to_raw_bytes`, against `language`'s wordlist - must match whatever
+    /// wordlist `from_raw_bytes_unchecked_in` encoded with.
+    pub fn to_raw_bytes_in(&self, language: Language) -> Vec<u8> {
+        let wordlist = language.word_list();
+
+        let mut bits: Vec<bool> = Vec::with_capacity(self.words.len() * 11);
+        for word in &self.words {
+            let index = wordlist.iter().position(|w| w == word).unwrap_or(0);
+            for bit in (0..11).rev() {
+                bits.push((index >> bit) & 1 == 1);
+            }
+        }
+
+        let mut bytes = vec![0u8; self.byte_len];
+        for (i, bit) in bits.iter().enumerate().take(self.byte_len * 8) {
+            if *bit {
+                bytes[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+
+        bytes
+    }
+
+    /// Rebuilds a `RawMnemonic` from words transcribed off paper. `byte_len`
+    /// must be the exact original payload length - the zero-padding bits
+    /// `from_raw_bytes_unchecked` appended to the final word are otherwise
+    /// indistinguishable from real payload bits, so the caller has to know
+    /// (and record alongside the words) how many bytes they're decoding back
+    /// to. See `shard::EncryptedShare::from_words` for a caller that does.
+    pub fn from_words(words: Vec<String>, byte_len: usize) -> Self {
+        Self { words, byte_len }
+    }
+
+    /// The encoded words, in order.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// The encoded words, space-joined.
+    pub fn phrase(&self) -> String {
+        self.words.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(bytes: &[u8]) {
+        let encoded = RawMnemonic::from_raw_bytes_unchecked(bytes);
+        assert_eq!(encoded.to_raw_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_round_trip_byte_aligned_lengths() {
+        round_trip(&[0u8; 16]);
+        round_trip(&[0xFFu8; 32]);
+        round_trip(&(0..22u8).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_round_trip_lengths_not_a_multiple_of_11_bits() {
+        // 11 bits = 1.375 bytes, so no non-empty byte length is ever an
+        // exact multiple - every one of these exercises the zero-padding
+        // on the final 11-bit group.
+        for len in [1usize, 2, 3, 5, 7, 12, 17, 24] {
+            let bytes: Vec<u8> = (0..len as u8).collect();
+            round_trip(&bytes);
+        }
+    }
+
+    #[test]
+    fn test_empty_payload_round_trips() {
+        round_trip(&[]);
+    }
+
+    #[test]
+    fn test_word_count_covers_every_payload_bit() {
+        // 33 bits of payload need ceil(33/11) = 3 words, not 2.
+        let encoded = RawMnemonic::from_raw_bytes_unchecked(&[0xFF; 5]);
+        assert_eq!(encoded.words().len(), 4);
+    }
+}
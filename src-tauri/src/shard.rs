@@ -0,0 +1,311 @@
+//! Threshold (`t`-of-`n`) splitting of a mnemonic's entropy into shares, so
+//! no single custodian holds the full secret.
+//!
+//! Entropy is split byte-by-byte with Shamir's scheme over GF(256): each
+//! byte becomes the constant term of a fresh random degree-`(t-1)`
+//! polynomial, and share `i` holds that polynomial evaluated at `x = i`.
+//! Any `t` shares recover every byte via Lagrange interpolation at `x = 0`;
+//! fewer than `t` reveal nothing. Each share is then sealed to one
+//! recipient's X25519 public key exactly the way `multi_recipient` wraps a
+//! content key: ECDH with a fresh ephemeral keypair, HKDF-SHA256 to derive
+//! an AES-256-GCM key, random nonce. A share's ciphertext can also be
+//! rendered as a `RawMnemonic` word list for a paper backup.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use x25519_dalek::PublicKey;
+
+use crate::mnemonic::{Mnemonic, MnemonicError, SeedPhraseFormatter};
+use crate::multi_recipient::{decode, decode_public_key, derive_wrap_key, encode, X25519KeyPair};
+use crate::raw_mnemonic::RawMnemonic;
+
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const SHARE_WRAP_INFO: &[u8] = b"btc-mnemonic-encryptor:shard-wrap";
+
+#[derive(Error, Debug)]
+pub enum ShardError {
+    #[error("at least one recipient public key is required")]
+    NoRecipients,
+
+    #[error("threshold must be between 2 and the number of recipients ({0})")]
+    InvalidThreshold(usize),
+
+    #[error("invalid public key bytes")]
+    InvalidKey,
+
+    #[error("invalid base64 data: {0}")]
+    InvalidData(String),
+
+    #[error("share encryption failed: {0}")]
+    EncryptionFailed(String),
+
+    #[error("share decryption failed: {0}")]
+    DecryptionFailed(String),
+
+    #[error("shares are not all the same length")]
+    MismatchedShareLength,
+
+    #[error("need at least {required} shares to reconstruct, got {provided}")]
+    InsufficientShares { required: u8, provided: usize },
+
+    #[error(transparent)]
+    Mnemonic(#[from] MnemonicError),
+}
+
+impl Serialize for ShardError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// One recipient's encrypted share, carrying everything needed to decrypt
+/// it with the matching private key and, once decrypted, to recombine it
+/// with the other shares.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EncryptedShare {
+    /// The `x` coordinate this share was evaluated at (1-based; never 0).
+    pub share_index: u8,
+    /// The threshold `combine_shares` needs at least this many of to
+    /// reconstruct, carried alongside the share so a custodian can tell
+    /// whether they hold enough shares before collecting decrypted copies.
+    pub threshold: u8,
+    pub ephemeral_public_key: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+impl EncryptedShare {
+    /// Renders this share as a `RawMnemonic` word list suitable for a paper
+    /// backup. The companion `byte_len` (print it alongside the words) is
+    /// required to reconstruct the share with `from_words`.
+    pub fn to_words(&self) -> Result<(RawMnemonic, usize), ShardError> {
+        let ephemeral_public_key = decode_public_key(&self.ephemeral_public_key).map_err(|_| ShardError::InvalidKey)?;
+        let nonce = decode(&self.nonce).map_err(|e| ShardError::InvalidData(e.to_string()))?;
+        let ciphertext = decode(&self.ciphertext).map_err(|e| ShardError::InvalidData(e.to_string()))?;
+
+        let mut blob = Vec::with_capacity(2 + ephemeral_public_key.len() + nonce.len() + ciphertext.len());
+        blob.push(self.share_index);
+        blob.push(self.threshold);
+        blob.extend_from_slice(&ephemeral_public_key);
+        blob.extend_from_slice(&nonce);
+        blob.extend_from_slice(&ciphertext);
+
+        let byte_len = blob.len();
+        Ok((RawMnemonic::from_raw_bytes_unchecked(&blob), byte_len))
+    }
+
+    /// Inverts `to_words`: `byte_len` must be the value `to_words` returned
+    /// alongside the `RawMnemonic` being decoded.
+    pub fn from_words(words: Vec<String>, byte_len: usize) -> Result<Self, ShardError> {
+        let blob = RawMnemonic::from_words(words, byte_len).to_raw_bytes();
+        if blob.len() < 2 + KEY_SIZE + NONCE_SIZE {
+            return Err(ShardError::InvalidData("share is too short to contain a header, ephemeral key, and nonce".to_string()));
+        }
+
+        let share_index = blob[0];
+        let threshold = blob[1];
+        let ephemeral_public_key = &blob[2..2 + KEY_SIZE];
+        let nonce = &blob[2 + KEY_SIZE..2 + KEY_SIZE + NONCE_SIZE];
+        let ciphertext = &blob[2 + KEY_SIZE + NONCE_SIZE..];
+
+        Ok(Self {
+            share_index,
+            threshold,
+            ephemeral_public_key: encode(ephemeral_public_key),
+            nonce: encode(nonce),
+            ciphertext: encode(ciphertext),
+        })
+    }
+}
+
+/// A share after decryption, ready to be handed to `combine_shares`
+/// alongside `threshold - 1` others.
+pub struct DecryptedShare {
+    share_index: u8,
+    threshold: u8,
+    bytes: Vec<u8>,
+}
+
+/// GF(256) multiplication, reduced modulo the AES polynomial `x^8 + x^4 +
+/// x^3 + x + 1` (`0x11B`).
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1B;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// `a` raised to the `n`th power in GF(256), by repeated squaring.
+fn gf_pow(a: u8, mut n: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        n >>= 1;
+    }
+    result
+}
+
+/// Multiplicative inverse in GF(256) via Fermat's little theorem: every
+/// nonzero element has order dividing 255, so `a^254 == a^-1`.
+fn gf_inv(a: u8) -> u8 {
+    gf_pow(a, 254)
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    gf_mul(a, gf_inv(b))
+}
+
+/// Evaluates the polynomial with `coefficients` (lowest degree first) at
+/// `x`, via Horner's method in GF(256).
+fn eval_poly(coefficients: &[u8], x: u8) -> u8 {
+    coefficients.iter().rev().fold(0u8, |acc, &coefficient| gf_mul(acc, x) ^ coefficient)
+}
+
+/// Lagrange-interpolates the constant term (the value at `x = 0`) of the
+/// polynomial passing through `points`.
+fn interpolate_at_zero(points: &[(u8, u8)]) -> u8 {
+    let mut secret = 0u8;
+    for &(x_i, y_i) in points {
+        let mut basis = 1u8;
+        for &(x_j, _) in points {
+            if x_j != x_i {
+                basis = gf_mul(basis, gf_div(x_j, x_i ^ x_j));
+            }
+        }
+        secret ^= gf_mul(y_i, basis);
+    }
+    secret
+}
+
+fn seal_share(share_index: u8, threshold: u8, share_bytes: &[u8], recipient_bytes: &[u8; KEY_SIZE]) -> Result<EncryptedShare, ShardError> {
+    let ephemeral = X25519KeyPair::generate();
+    let ephemeral_public_bytes = ephemeral.public_key_bytes();
+    let recipient_public = PublicKey::from(*recipient_bytes);
+    let shared_secret = ephemeral.diffie_hellman(&recipient_public);
+    let wrap_key = derive_wrap_key(shared_secret.as_bytes(), &ephemeral_public_bytes, recipient_bytes);
+
+    let mut nonce_bytes = [0u8; NONCE_SIZE];
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let cipher = Aes256Gcm::new_from_slice(&wrap_key).map_err(|e| ShardError::EncryptionFailed(e.to_string()))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), share_bytes)
+        .map_err(|e| ShardError::EncryptionFailed(e.to_string()))?;
+
+    Ok(EncryptedShare {
+        share_index,
+        threshold,
+        ephemeral_public_key: encode(&ephemeral_public_bytes),
+        nonce: encode(&nonce_bytes),
+        ciphertext: encode(&ciphertext),
+    })
+}
+
+/// Splits `mnemonic`'s entropy into one sealed share per entry in
+/// `recipient_public_keys`, such that any `threshold` of them reconstruct
+/// it and fewer than `threshold` reveal nothing.
+pub fn split_mnemonic(mnemonic: &str, threshold: u8, recipient_public_keys: &[[u8; KEY_SIZE]]) -> Result<Vec<EncryptedShare>, ShardError> {
+    let share_count = recipient_public_keys.len();
+    if share_count == 0 {
+        return Err(ShardError::NoRecipients);
+    }
+    if threshold < 2 || threshold as usize > share_count {
+        return Err(ShardError::InvalidThreshold(share_count));
+    }
+
+    let format_result = SeedPhraseFormatter::format_seed_phrase_comprehensive(mnemonic);
+    let entropy = SeedPhraseFormatter::to_entropy(&format_result)?;
+
+    let mut share_bytes: Vec<Vec<u8>> = vec![Vec::with_capacity(entropy.len()); share_count];
+    for &secret_byte in &entropy {
+        let mut coefficients = Vec::with_capacity(threshold as usize);
+        coefficients.push(secret_byte);
+        for _ in 1..threshold {
+            let mut random_byte = [0u8; 1];
+            OsRng.fill_bytes(&mut random_byte);
+            coefficients.push(random_byte[0]);
+        }
+
+        for (index, share) in share_bytes.iter_mut().enumerate() {
+            let x = (index + 1) as u8;
+            share.push(eval_poly(&coefficients, x));
+        }
+    }
+
+    recipient_public_keys
+        .iter()
+        .zip(share_bytes)
+        .enumerate()
+        .map(|(index, (recipient_bytes, share))| seal_share((index + 1) as u8, threshold, &share, recipient_bytes))
+        .collect()
+}
+
+/// Unwraps one encrypted share with `keypair`'s private key. The result
+/// still needs `threshold - 1` others before `combine_shares` can
+/// reconstruct the mnemonic.
+pub fn decrypt_share(share: &EncryptedShare, keypair: &X25519KeyPair) -> Result<DecryptedShare, ShardError> {
+    let ephemeral_public_bytes = decode_public_key(&share.ephemeral_public_key).map_err(|_| ShardError::InvalidKey)?;
+    let ephemeral_public = PublicKey::from(ephemeral_public_bytes);
+    let my_public_bytes = keypair.public_key_bytes();
+    let shared_secret = keypair.diffie_hellman(&ephemeral_public);
+    let wrap_key = derive_wrap_key(shared_secret.as_bytes(), &ephemeral_public_bytes, &my_public_bytes);
+
+    let nonce = decode(&share.nonce).map_err(|e| ShardError::InvalidData(e.to_string()))?;
+    let ciphertext = decode(&share.ciphertext).map_err(|e| ShardError::InvalidData(e.to_string()))?;
+
+    let cipher = Aes256Gcm::new_from_slice(&wrap_key).map_err(|e| ShardError::DecryptionFailed(e.to_string()))?;
+    let bytes = cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|e| ShardError::DecryptionFailed(e.to_string()))?;
+
+    Ok(DecryptedShare { share_index: share.share_index, threshold: share.threshold, bytes })
+}
+
+/// Reconstructs the original mnemonic from `threshold` (or more) decrypted
+/// shares, via GF(256) Lagrange interpolation of every entropy byte.
+pub fn combine_shares(shares: &[DecryptedShare]) -> Result<Mnemonic, ShardError> {
+    let Some(first) = shares.first() else {
+        return Err(ShardError::InsufficientShares { required: 2, provided: 0 });
+    };
+    let threshold = first.threshold;
+    if shares.len() < threshold as usize {
+        return Err(ShardError::InsufficientShares { required: threshold, provided: shares.len() });
+    }
+
+    let share_len = first.bytes.len();
+    if shares.iter().any(|share| share.bytes.len() != share_len) {
+        return Err(ShardError::MismatchedShareLength);
+    }
+
+    let mut entropy = Vec::with_capacity(share_len);
+    for byte_index in 0..share_len {
+        let points: Vec<(u8, u8)> = shares.iter().take(threshold as usize).map(|share| (share.share_index, share.bytes[byte_index])).collect();
+        entropy.push(interpolate_at_zero(&points));
+    }
+
+    let phrase = SeedPhraseFormatter::from_entropy(&entropy)?;
+    let word_count = phrase.split_whitespace().count();
+    Ok(Mnemonic { phrase, word_count, language: "english".to_string() })
+}
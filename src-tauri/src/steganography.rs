@@ -0,0 +1,130 @@
+use image::{ImageFormat, Rgba, RgbaImage};
+use std::io::Cursor;
+use thiserror::Error;
+
+/// Bytes reserved at the front of the embedded payload for a little-endian
+/// `u32` length header, so extraction knows exactly where the payload ends
+/// instead of reading trailing carrier noise.
+const LENGTH_HEADER_BYTES: usize = 4;
+
+#[derive(Error, Debug)]
+pub enum StegoError {
+    #[error("failed to decode carrier image: {0}")]
+    DecodeFailed(String),
+    #[error("failed to encode stego image: {0}")]
+    EncodeFailed(String),
+    #[error("carrier image is too small to hold this payload: needs {needed} bytes of capacity, has {available}")]
+    PayloadTooLarge { needed: usize, available: usize },
+    #[error("extracted payload is not valid UTF-8: {0}")]
+    InvalidUtf8(String),
+}
+
+impl serde::Serialize for StegoError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// How many payload bytes (including the length header) fit in `image`,
+/// spreading one payload bit per least-significant bit of each R/G/B
+/// sub-pixel (alpha is left untouched so transparency is preserved).
+fn capacity_bytes(image: &RgbaImage) -> usize {
+    (image.width() as usize * image.height() as usize * 3) / 8
+}
+
+/// Hides `payload` inside the least-significant bits of `carrier_png`'s RGB
+/// channels, prefixed by a 4-byte little-endian length header, and returns
+/// the re-encoded PNG bytes. The result is visually indistinguishable from
+/// the carrier but decodes back to the exact original `payload` string.
+pub fn embed_in_image(carrier_png: &[u8], payload: &str) -> Result<Vec<u8>, StegoError> {
+    let image = image::load_from_memory(carrier_png)
+        .map_err(|e| StegoError::DecodeFailed(e.to_string()))?
+        .to_rgba8();
+
+    let payload_bytes = payload.as_bytes();
+    let needed = LENGTH_HEADER_BYTES + payload_bytes.len();
+    let available = capacity_bytes(&image);
+    if needed > available {
+        return Err(StegoError::PayloadTooLarge { needed, available });
+    }
+
+    let mut carrier_bits = Vec::with_capacity(needed * 8);
+    for byte in (payload_bytes.len() as u32)
+        .to_le_bytes()
+        .iter()
+        .chain(payload_bytes.iter())
+    {
+        for bit_index in (0..8).rev() {
+            carrier_bits.push((byte >> bit_index) & 1);
+        }
+    }
+
+    let mut stego_image = image;
+    let mut bits = carrier_bits.into_iter();
+    'pixels: for pixel in stego_image.pixels_mut() {
+        for channel in 0..3 {
+            let Some(bit) = bits.next() else {
+                break 'pixels;
+            };
+            pixel[channel] = (pixel[channel] & 0b1111_1110) | bit;
+        }
+    }
+
+    let mut encoded = Vec::new();
+    stego_image
+        .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Png)
+        .map_err(|e| StegoError::EncodeFailed(e.to_string()))?;
+    Ok(encoded)
+}
+
+/// Reverses [`embed_in_image`]: reads the length header out of the
+/// least-significant bits, then reads exactly that many payload bytes and
+/// decodes them as UTF-8.
+pub fn extract_from_image(stego_png: &[u8]) -> Result<String, StegoError> {
+    let image = image::load_from_memory(stego_png)
+        .map_err(|e| StegoError::DecodeFailed(e.to_string()))?
+        .to_rgba8();
+
+    let mut bits = image
+        .pixels()
+        .flat_map(|pixel: &Rgba<u8>| (0..3).map(move |channel| pixel[channel] & 1));
+
+    let mut read_byte = || -> Option<u8> {
+        let mut byte = 0u8;
+        for _ in 0..8 {
+            byte = (byte << 1) | bits.next()?;
+        }
+        Some(byte)
+    };
+
+    let mut length_bytes = [0u8; LENGTH_HEADER_BYTES];
+    for slot in length_bytes.iter_mut() {
+        *slot = read_byte().ok_or_else(|| {
+            StegoError::DecodeFailed("image is too small to contain a length header".to_string())
+        })?;
+    }
+    let payload_len = u32::from_le_bytes(length_bytes) as usize;
+
+    // `payload_len` came straight out of the untrusted image's LSBs - up to
+    // ~4GiB for a corrupt or maliciously crafted carrier. The carrier can
+    // only ever hold `capacity_bytes(&image)` bytes total, so reject before
+    // reserving anything rather than letting a bogus length trigger a
+    // multi-gigabyte allocation.
+    let needed = LENGTH_HEADER_BYTES + payload_len;
+    let available = capacity_bytes(&image);
+    if needed > available {
+        return Err(StegoError::PayloadTooLarge { needed, available });
+    }
+
+    let mut payload_bytes = Vec::with_capacity(payload_len);
+    for _ in 0..payload_len {
+        payload_bytes.push(read_byte().ok_or_else(|| {
+            StegoError::DecodeFailed("image ended before the full payload was read".to_string())
+        })?);
+    }
+
+    String::from_utf8(payload_bytes).map_err(|e| StegoError::InvalidUtf8(e.to_string()))
+}
@@ -0,0 +1,246 @@
+use crate::crypto::KdfParams;
+use aes_gcm::{
+    aead::{Aead, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use rand::RngCore;
+use std::io::{Read, Write};
+use thiserror::Error;
+use zeroize::Zeroize;
+
+/// Plaintext is split into chunks this size (64 KiB) before encryption, so a
+/// multi-gigabyte wallet backup never needs to be held in memory whole.
+pub const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Bytes of the STREAM nonce drawn once per file and stored in the header;
+/// the remaining 5 bytes (a 4-byte big-endian chunk counter and a 1-byte
+/// last-chunk flag) are derived per chunk so every chunk gets a distinct
+/// nonce under the same key.
+const NONCE_PREFIX_SIZE: usize = 7;
+const CHUNK_COUNTER_SIZE: usize = 4;
+
+const MAGIC: &[u8; 8] = b"AESSTR01";
+const KDF_PARAMS_SIZE: usize = 12;
+
+/// Tag appended to every AES-256-GCM chunk ciphertext.
+const TAG_SIZE: usize = 16;
+
+#[derive(Error, Debug)]
+pub enum StreamError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to create cipher: {0}")]
+    CipherSetup(String),
+    #[error("chunk encryption failed: {0}")]
+    EncryptionFailed(String),
+    #[error("stream is truncated or its header is malformed")]
+    Truncated,
+    #[error("unrecognized stream magic/version")]
+    InvalidMagic,
+    #[error("chunk {0} failed authentication (stream may be truncated, reordered, or tampered with)")]
+    ChunkAuthenticationFailed(u32),
+    #[error("a chunk marked as final was followed by more data")]
+    DataAfterFinalChunk,
+    #[error("stream ended without a chunk marked as final")]
+    MissingFinalChunk,
+}
+
+impl serde::Serialize for StreamError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Builds the 12-byte STREAM nonce for one chunk: a random per-file prefix,
+/// a big-endian chunk counter, and a last-chunk flag. Varying the counter
+/// and flag per chunk under a key that's otherwise reused for the whole file
+/// means truncating, reordering, or duplicating chunks changes the nonce an
+/// attacker would need to forge, so it's caught by the AEAD tag instead of
+/// silently accepted.
+fn chunk_nonce(prefix: &[u8; NONCE_PREFIX_SIZE], counter: u32, is_last: bool) -> [u8; 12] {
+    let mut nonce = [0u8; 12];
+    nonce[..NONCE_PREFIX_SIZE].copy_from_slice(prefix);
+    nonce[NONCE_PREFIX_SIZE..NONCE_PREFIX_SIZE + CHUNK_COUNTER_SIZE].copy_from_slice(&counter.to_be_bytes());
+    nonce[NONCE_PREFIX_SIZE + CHUNK_COUNTER_SIZE] = if is_last { 1 } else { 0 };
+    nonce
+}
+
+/// Encrypts `reader` to `writer` in fixed-size chunks under the STREAM
+/// construction, writing a self-describing header (magic, KDF params, salt,
+/// nonce prefix) followed by length-prefixed encrypted chunks. `key` must
+/// already be derived (see `KdfParams::derive_key`); `salt` and `kdf_params`
+/// are recorded in the header purely for `decrypt_stream` (and a human) to
+/// see what the key was derived with.
+pub fn encrypt_stream<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    key: &[u8; 32],
+    kdf_params: KdfParams,
+    salt: &[u8; 32],
+) -> Result<(), StreamError> {
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| StreamError::CipherSetup(e.to_string()))?;
+
+    let mut nonce_prefix = [0u8; NONCE_PREFIX_SIZE];
+    OsRng.fill_bytes(&mut nonce_prefix);
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[kdf_params.kdf_id()])?;
+    writer.write_all(&kdf_params.to_header_bytes())?;
+    writer.write_all(salt)?;
+    writer.write_all(&nonce_prefix)?;
+
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut counter: u32 = 0;
+    let mut pending: Option<Vec<u8>> = None;
+
+    loop {
+        let read_len = read_full(reader, &mut buffer)?;
+        let chunk = buffer[..read_len].to_vec();
+
+        // We only know a chunk is the *last* one once we see a short read or
+        // EOF, so each chunk is held back one iteration until the next
+        // read's outcome tells us whether to flag it final.
+        if let Some(previous) = pending.take() {
+            write_chunk(writer, &cipher, &nonce_prefix, counter, false, previous)?;
+            counter += 1;
+        }
+
+        if read_len < CHUNK_SIZE {
+            write_chunk(writer, &cipher, &nonce_prefix, counter, true, chunk)?;
+            return Ok(());
+        }
+
+        pending = Some(chunk);
+    }
+}
+
+fn write_chunk<W: Write>(
+    writer: &mut W,
+    cipher: &Aes256Gcm,
+    nonce_prefix: &[u8; NONCE_PREFIX_SIZE],
+    counter: u32,
+    is_last: bool,
+    mut plaintext: Vec<u8>,
+) -> Result<(), StreamError> {
+    let nonce_bytes = chunk_nonce(nonce_prefix, counter, is_last);
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext.as_ref())
+        .map_err(|e| StreamError::EncryptionFailed(e.to_string()))?;
+    plaintext.zeroize();
+
+    writer.write_all(&(ciphertext.len() as u32).to_le_bytes())?;
+    writer.write_all(&[if is_last { 1 } else { 0 }])?;
+    writer.write_all(&ciphertext)?;
+    Ok(())
+}
+
+/// Reads exactly `buffer.len()` bytes, or fewer at EOF, returning how many
+/// bytes were actually read (unlike `Read::read_exact`, a short read at EOF
+/// is not an error here — it's how the final chunk's size is discovered).
+fn read_full<R: Read>(reader: &mut R, buffer: &mut [u8]) -> std::io::Result<usize> {
+    let mut total = 0;
+    while total < buffer.len() {
+        let read = reader.read(&mut buffer[total..])?;
+        if read == 0 {
+            break;
+        }
+        total += read;
+    }
+    Ok(total)
+}
+
+/// The header fields `decrypt_stream` recovers before it can authenticate
+/// any chunk: which KDF derived the key and with what salt, so the caller
+/// can re-derive (or verify) the key, plus the per-file nonce prefix.
+pub struct StreamHeader {
+    pub kdf_params: KdfParams,
+    pub salt: [u8; 32],
+}
+
+/// Reads and validates the stream header, returning it alongside the nonce
+/// prefix needed to authenticate the chunks that follow.
+fn read_header<R: Read>(reader: &mut R) -> Result<(StreamHeader, [u8; NONCE_PREFIX_SIZE]), StreamError> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic).map_err(|_| StreamError::Truncated)?;
+    if &magic != MAGIC {
+        return Err(StreamError::InvalidMagic);
+    }
+
+    let mut kdf_id = [0u8; 1];
+    reader.read_exact(&mut kdf_id).map_err(|_| StreamError::Truncated)?;
+
+    let mut kdf_param_bytes = [0u8; KDF_PARAMS_SIZE];
+    reader.read_exact(&mut kdf_param_bytes).map_err(|_| StreamError::Truncated)?;
+    let kdf_params = KdfParams::from_header_bytes(kdf_id[0], &kdf_param_bytes);
+
+    let mut salt = [0u8; 32];
+    reader.read_exact(&mut salt).map_err(|_| StreamError::Truncated)?;
+
+    let mut nonce_prefix = [0u8; NONCE_PREFIX_SIZE];
+    reader.read_exact(&mut nonce_prefix).map_err(|_| StreamError::Truncated)?;
+
+    Ok((StreamHeader { kdf_params, salt }, nonce_prefix))
+}
+
+/// Decrypts a stream written by `encrypt_stream`, writing the recovered
+/// plaintext to `writer`. Verifies that chunk counters arrive in order with
+/// no gaps and that exactly one chunk — the last one read — is flagged
+/// final, so a truncated, reordered, or spliced stream is rejected instead
+/// of silently returning partial or wrong plaintext.
+pub fn decrypt_stream<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    key: &[u8; 32],
+) -> Result<StreamHeader, StreamError> {
+    let (header, nonce_prefix) = read_header(reader)?;
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| StreamError::CipherSetup(e.to_string()))?;
+
+    let mut counter: u32 = 0;
+    loop {
+        let mut len_bytes = [0u8; 4];
+        match reader.read_exact(&mut len_bytes) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                return Err(StreamError::MissingFinalChunk);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        let chunk_len = u32::from_le_bytes(len_bytes) as usize;
+        if chunk_len < TAG_SIZE {
+            return Err(StreamError::Truncated);
+        }
+
+        let mut is_last_byte = [0u8; 1];
+        reader.read_exact(&mut is_last_byte).map_err(|_| StreamError::Truncated)?;
+        let is_last = is_last_byte[0] != 0;
+
+        let mut ciphertext = vec![0u8; chunk_len];
+        reader.read_exact(&mut ciphertext).map_err(|_| StreamError::Truncated)?;
+
+        let nonce_bytes = chunk_nonce(&nonce_prefix, counter, is_last);
+        let mut plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce_bytes), ciphertext.as_ref())
+            .map_err(|_| StreamError::ChunkAuthenticationFailed(counter))?;
+
+        writer.write_all(&plaintext)?;
+        plaintext.zeroize();
+
+        if is_last {
+            // A conforming stream ends exactly here; any trailing bytes mean
+            // the stream was spliced with data from elsewhere.
+            let mut extra = [0u8; 1];
+            if reader.read(&mut extra)? != 0 {
+                return Err(StreamError::DataAfterFinalChunk);
+            }
+            return Ok(header);
+        }
+
+        counter += 1;
+    }
+}
@@ -0,0 +1,204 @@
+use bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv, Xpub};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{Address, CompressedPublicKey, Network};
+use pbkdf2::pbkdf2_hmac;
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::network::is_network_connected;
+
+const SEED_PBKDF2_ITERATIONS: u32 = 2048;
+const SEED_LEN: usize = 64;
+
+#[derive(Error, Debug)]
+pub enum WalletDeriveError {
+    #[error("refusing to derive wallet data while a network connection is active")]
+    NetworkDetected,
+    #[error("key derivation failed: {0}")]
+    DerivationFailed(String),
+}
+
+impl Serialize for WalletDeriveError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressSet {
+    /// "native_segwit" | "nested_segwit" | "legacy"
+    pub script_type: String,
+    pub derivation_path: String,
+    pub account_xpub: String,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WalletDescriptor {
+    pub native_segwit: AddressSet,
+    pub nested_segwit: AddressSet,
+    pub legacy: AddressSet,
+}
+
+/// Derives the 512-bit BIP39 seed from a mnemonic and optional passphrase
+/// (the "25th word"): PBKDF2-HMAC-SHA512 over the NFKD-normalized mnemonic,
+/// salted with `"mnemonic" + the NFKD-normalized passphrase`, 2048
+/// iterations. This is the seed BIP32 derives the wallet from; an empty
+/// passphrase must reproduce the published BIP39 test vectors, while a
+/// non-empty one derives an entirely different (and otherwise
+/// indistinguishable) wallet, enabling plausible-deniability hidden wallets.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> [u8; SEED_LEN] {
+    let normalized_mnemonic: String = mnemonic.nfkd().collect();
+    let normalized_passphrase: String = passphrase.nfkd().collect();
+    let salt = format!("mnemonic{}", normalized_passphrase);
+
+    let mut seed = [0u8; SEED_LEN];
+    pbkdf2_hmac::<Sha512>(
+        normalized_mnemonic.as_bytes(),
+        salt.as_bytes(),
+        SEED_PBKDF2_ITERATIONS,
+        &mut seed,
+    );
+    seed
+}
+
+fn derive_account(
+    master: &Xpriv,
+    secp: &Secp256k1<bitcoin::secp256k1::All>,
+    path: &str,
+) -> Result<(Xpriv, Xpub), WalletDeriveError> {
+    let derivation_path: DerivationPath = path
+        .parse()
+        .map_err(|e| WalletDeriveError::DerivationFailed(format!("invalid path {}: {}", path, e)))?;
+    let account_priv = master
+        .derive_priv(secp, &derivation_path)
+        .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?;
+    let account_pub = Xpub::from_priv(secp, &account_priv);
+    Ok((account_priv, account_pub))
+}
+
+fn receive_addresses(
+    account_priv: &Xpriv,
+    secp: &Secp256k1<bitcoin::secp256k1::All>,
+    network: Network,
+    script_type: &str,
+    count: u32,
+) -> Result<Vec<String>, WalletDeriveError> {
+    let mut addresses = Vec::with_capacity(count as usize);
+    for index in 0..count {
+        // m/<account>/0/<index> — external (receive) chain
+        let child_path = [
+            ChildNumber::from_normal_idx(0)
+                .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?,
+            ChildNumber::from_normal_idx(index)
+                .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?,
+        ];
+        let child = account_priv
+            .derive_priv(secp, &child_path)
+            .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?;
+        let compressed = CompressedPublicKey::from_private_key(secp, &child.to_priv())
+            .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?;
+
+        let address = match script_type {
+            "native_segwit" => Address::p2wpkh(&compressed, network),
+            "nested_segwit" => Address::p2shwpkh(&compressed, network),
+            _ => Address::p2pkh(compressed.0, network),
+        };
+        addresses.push(address.to_string());
+    }
+    Ok(addresses)
+}
+
+/// Derives account-level xpubs and the first `address_count` receive
+/// addresses for the standard native segwit (m/84'/0'/0'), nested segwit
+/// (m/49'/0'/0'), and legacy (m/44'/0'/0') paths — entirely offline.
+///
+/// This is a read-only sanity check ("did I encrypt the right seed?"), so it
+/// refuses to run while a network connection is detected, matching
+/// `check_enhanced_network_security`'s stance on seed-phrase handling.
+pub async fn derive_wallet_descriptor(
+    mnemonic: &str,
+    bip39_passphrase: &str,
+    address_count: u32,
+) -> Result<WalletDescriptor, WalletDeriveError> {
+    if is_network_connected().await {
+        return Err(WalletDeriveError::NetworkDetected);
+    }
+
+    let seed = mnemonic_to_seed(mnemonic, bip39_passphrase);
+    let secp = Secp256k1::new();
+    let network = Network::Bitcoin;
+
+    let master = Xpriv::new_master(network, &seed)
+        .map_err(|e| WalletDeriveError::DerivationFailed(e.to_string()))?;
+
+    let paths = [
+        ("native_segwit", "m/84'/0'/0'"),
+        ("nested_segwit", "m/49'/0'/0'"),
+        ("legacy", "m/44'/0'/0'"),
+    ];
+
+    let mut sets = Vec::with_capacity(3);
+    for (script_type, path) in paths {
+        let (account_priv, account_pub) = derive_account(&master, &secp, path)?;
+        let addresses = receive_addresses(&account_priv, &secp, network, script_type, address_count)?;
+        sets.push(AddressSet {
+            script_type: script_type.to_string(),
+            derivation_path: path.to_string(),
+            account_xpub: account_pub.to_string(),
+            addresses,
+        });
+    }
+
+    let mut sets = sets.into_iter();
+    Ok(WalletDescriptor {
+        native_segwit: sets.next().unwrap(),
+        nested_segwit: sets.next().unwrap(),
+        legacy: sets.next().unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    // Standard Trezor BIP39 test vectors (the canonical vectors.json
+    // distributed with python-mnemonic / the BIP39 reference test suite),
+    // all using the "TREZOR" passphrase.
+    #[test]
+    fn test_trezor_vector_12_word_all_abandon() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let seed = mnemonic_to_seed(mnemonic, "TREZOR");
+        assert_eq!(
+            to_hex(&seed),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn test_trezor_vector_24_word_all_zoo() {
+        let mnemonic = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+        let seed = mnemonic_to_seed(mnemonic, "TREZOR");
+        assert_eq!(
+            to_hex(&seed),
+            "ad25e0925f3e97df9b35a7a6eb6c1195ec58dbe35aa00ec9c3ff0fd2e11cba3dff31ee9a3b62c8c01bf5322cddf3cb589732d2a798e33a92e75e30c5c09cb1ce"
+        );
+    }
+
+    #[test]
+    fn test_different_passphrase_derives_different_seed() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let no_passphrase = mnemonic_to_seed(mnemonic, "");
+        let with_passphrase = mnemonic_to_seed(mnemonic, "hidden wallet");
+        assert_ne!(no_passphrase, with_passphrase);
+    }
+}